@@ -0,0 +1,59 @@
+//! A demonstration of tooltip content built in the background on the async compute task pool.
+
+use core::time::Duration;
+
+use bevy::prelude::*;
+use bevy::tasks::AsyncComputeTaskPool;
+use bevy::ui::Val::*;
+use pyri_tooltip::prelude::*;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins((DefaultPlugins, TooltipPlugin::default()))
+        .add_systems(Startup, spawn_scene)
+        .run()
+}
+
+/// A marker component for targets whose tooltip content hasn't been built yet.
+#[derive(Component)]
+struct Unbuilt;
+
+fn spawn_scene(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    commands
+        .spawn((
+            Node {
+                width: Px(64.0),
+                height: Px(64.0),
+                align_self: AlignSelf::Center,
+                justify_self: JustifySelf::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.2, 0.4, 0.6)),
+            Tooltip::cursor("Loading..."),
+            Unbuilt,
+        ))
+        .observe(build_content_on_hover);
+}
+
+// Start building the content the first time the target is hovered.
+fn build_content_on_hover(
+    trigger: Trigger<Pointer<Over>>,
+    mut commands: Commands,
+    unbuilt_query: Query<(), With<Unbuilt>>,
+) {
+    let entity = trigger.target();
+    if !unbuilt_query.contains(entity) {
+        return;
+    }
+
+    let task = AsyncComputeTaskPool::get().spawn(async move {
+        // Simulate an expensive content build.
+        std::thread::sleep(Duration::from_secs(1));
+        TooltipContent::from(format!("Built in the background for {entity}"))
+    });
+    commands
+        .entity(entity)
+        .remove::<Unbuilt>()
+        .insert(TooltipContentTask(task));
+}
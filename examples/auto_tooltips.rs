@@ -0,0 +1,42 @@
+//! A demonstration of tooltips generated automatically from each entity's `Name`.
+
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use pyri_tooltip::{AutoTooltip, prelude::*};
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins((DefaultPlugins, TooltipPlugin::default()))
+        .add_systems(Startup, spawn_scene)
+        .run()
+}
+
+fn spawn_scene(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    commands.spawn((
+        Node {
+            align_self: AlignSelf::Center,
+            justify_self: JustifySelf::Center,
+            column_gap: Px(8.0),
+            ..default()
+        },
+        children![
+            tile("Fire", Color::srgb(1.0, 0.3, 0.0)),
+            tile("Water", Color::srgb(0.0, 0.4, 1.0)),
+            tile("Earth", Color::srgb(0.4, 0.3, 0.1)),
+        ],
+    ));
+}
+
+fn tile(name: &'static str, color: Color) -> impl Bundle {
+    (
+        Name::new(name),
+        AutoTooltip,
+        Node {
+            width: Px(64.0),
+            height: Px(64.0),
+            ..default()
+        },
+        BackgroundColor(color),
+    )
+}
@@ -0,0 +1,49 @@
+//! A demonstration of a backdrop that dims the rest of the UI while a tooltip is active.
+
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use bevy::ui::Val::*;
+use pyri_tooltip::prelude::*;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins((DefaultPlugins, TooltipPlugin::default()))
+        .add_systems(Startup, (enable_backdrop, spawn_scene))
+        .run()
+}
+
+fn enable_backdrop(mut settings: ResMut<TooltipSettings>) {
+    settings.backdrop = Some(TooltipBackdrop {
+        color: Color::srgba(0.0, 0.0, 0.0, 0.6),
+        fade: 200,
+    });
+}
+
+fn spawn_scene(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    commands.spawn((
+        Node {
+            align_self: AlignSelf::Center,
+            justify_self: JustifySelf::Center,
+            column_gap: Px(16.0),
+            ..default()
+        },
+        children![
+            tile(Color::srgb(1.0, 0.0, 0.0), "Red"),
+            tile(Color::srgb(0.0, 1.0, 0.0), "Green"),
+            tile(Color::srgb(0.0, 0.0, 1.0), "Blue"),
+        ],
+    ));
+}
+
+fn tile(color: Color, text: &'static str) -> impl Bundle {
+    (
+        Node {
+            width: Px(64.0),
+            height: Px(64.0),
+            ..default()
+        },
+        BackgroundColor(color),
+        Tooltip::fixed(Anchor::TopCenter, text),
+    )
+}
@@ -0,0 +1,48 @@
+//! A demonstration of the fallback tooltip for debugging entities without a `Tooltip`.
+
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use pyri_tooltip::prelude::*;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins((DefaultPlugins, TooltipPlugin::default()))
+        .add_systems(Startup, (enable_fallback_tooltip, spawn_scene))
+        .run()
+}
+
+fn enable_fallback_tooltip(mut settings: ResMut<TooltipSettings>) {
+    settings.fallback = Some(FallbackTooltip::default());
+}
+
+fn spawn_scene(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    commands.spawn((
+        Name::new("Panel"),
+        Node {
+            align_self: AlignSelf::Center,
+            justify_self: JustifySelf::Center,
+            column_gap: Px(8.0),
+            padding: UiRect::all(Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+        children![
+            (Name::new("RedTile"), tile(Color::srgb(1.0, 0.0, 0.0))),
+            (Name::new("GreenTile"), tile(Color::srgb(0.0, 1.0, 0.0))),
+            // Entities without a `Name` display their `Entity` ID instead.
+            tile(Color::srgb(0.0, 0.0, 1.0)),
+        ],
+    ));
+}
+
+fn tile(color: Color) -> impl Bundle {
+    (
+        Node {
+            width: Px(64.0),
+            height: Px(64.0),
+            ..default()
+        },
+        BackgroundColor(color),
+    )
+}
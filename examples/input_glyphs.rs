@@ -0,0 +1,47 @@
+//! A demonstration of tooltips that display the current binding of an input action.
+
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use pyri_tooltip::prelude::*;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins((DefaultPlugins, TooltipPlugin::default()))
+        .insert_resource(InputGlyphResolver::new(resolve_glyph))
+        .add_systems(Startup, spawn_scene)
+        .run()
+}
+
+// In a real game, this would look up the current binding in an input map.
+fn resolve_glyph(action: &str) -> Option<String> {
+    match action {
+        "interact" => Some("[E]".to_string()),
+        _ => None,
+    }
+}
+
+fn spawn_scene(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    commands.spawn((
+        Node {
+            width: Px(64.0),
+            height: Px(64.0),
+            align_self: AlignSelf::Center,
+            justify_self: JustifySelf::Center,
+            ..default()
+        },
+        BackgroundColor(Color::WHITE),
+        Tooltip::cursor(vec![
+            TextSection::new("Press ", TextStyle::default()),
+            TextSection::from_glyph(
+                "interact",
+                "[Interact]",
+                TextStyle {
+                    color: Color::srgb(1.0, 0.8, 0.2),
+                    ..default()
+                },
+            ),
+            TextSection::new(" to interact", TextStyle::default()),
+        ]),
+    ));
+}
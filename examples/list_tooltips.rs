@@ -0,0 +1,61 @@
+//! A demonstration of tooltips with ordered and unordered lists.
+
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use pyri_tooltip::prelude::*;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins((DefaultPlugins, TooltipPlugin::default()))
+        .add_systems(Startup, spawn_scene)
+        .run()
+}
+
+fn spawn_scene(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    commands
+        .spawn(Node {
+            width: Percent(100.0),
+            height: Percent(100.0),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            column_gap: Px(32.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    width: Px(64.0),
+                    height: Px(64.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.8, 0.3, 0.2)),
+                Tooltip::cursor(TooltipContent::list(
+                    [
+                        TooltipListItem::from("Fire damage"),
+                        TooltipListItem::new("Burns on hit")
+                            .with_sub_items(["Lasts 3 seconds", "Stacks up to 5 times"]),
+                        TooltipListItem::from("Lights up dark areas"),
+                    ],
+                    false,
+                )),
+            ));
+            parent.spawn((
+                Node {
+                    width: Px(64.0),
+                    height: Px(64.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.2, 0.4, 0.8)),
+                Tooltip::cursor(TooltipContent::list(
+                    [
+                        TooltipListItem::from("Open the map"),
+                        TooltipListItem::new("Select a destination")
+                            .with_sub_items(["Towns", "Dungeons"]),
+                        TooltipListItem::from("Confirm to travel"),
+                    ],
+                    true,
+                )),
+            ));
+        });
+}
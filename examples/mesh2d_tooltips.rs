@@ -0,0 +1,35 @@
+//! A demonstration of tooltips on `Mesh2d` shapes.
+//!
+//! `Mesh2d` entities are hovered through `MeshPickingPlugin`, which ray casts against the actual
+//! mesh, so the tooltip only activates within the circle rather than its bounding box. Fixed
+//! placement anchors the tooltip to the mesh's bounds.
+
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use pyri_tooltip::prelude::*;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins((DefaultPlugins, MeshPickingPlugin, TooltipPlugin::default()))
+        .add_systems(Startup, spawn_scene)
+        .run()
+}
+
+fn spawn_scene(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    commands.spawn(Camera2d);
+    commands.spawn((
+        Mesh2d(meshes.add(Circle::new(64.0))),
+        MeshMaterial2d(materials.add(Color::srgb(0.2, 0.6, 0.4))),
+        Tooltip::cursor("A circle"),
+    ));
+    commands.spawn((
+        Mesh2d(meshes.add(Rectangle::new(96.0, 96.0))),
+        MeshMaterial2d(materials.add(Color::srgb(0.6, 0.2, 0.4))),
+        Transform::from_xyz(200.0, 0.0, 0.0),
+        Tooltip::fixed(Anchor::TopCenter, "A square"),
+    ));
+}
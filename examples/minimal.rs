@@ -0,0 +1,27 @@
+//! A minimal tooltip setup that relies on the default primary tooltip container.
+
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use pyri_tooltip::prelude::*;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins((DefaultPlugins, TooltipPlugin::default()))
+        .add_systems(Startup, spawn_scene)
+        .run()
+}
+
+fn spawn_scene(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    commands.spawn((
+        Node {
+            width: Px(64.0),
+            height: Px(64.0),
+            align_self: AlignSelf::Center,
+            justify_self: JustifySelf::Center,
+            ..default()
+        },
+        BackgroundColor(Color::WHITE),
+        Tooltip::cursor("Hello, world!"),
+    ));
+}
@@ -0,0 +1,32 @@
+//! A demonstration of a multi-line tooltip with an individually styled line.
+
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use pyri_tooltip::prelude::*;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins((DefaultPlugins, TooltipPlugin::default()))
+        .add_systems(Startup, spawn_scene)
+        .run()
+}
+
+fn spawn_scene(mut commands: Commands) {
+    let mut content = TooltipContent::lines(["Iron Sword", "Damage: 12", "Weight: 3 kg"]);
+    if let TooltipContent::Primary(text) = &mut content {
+        text.sections[0].style.color = Color::srgb(1.0, 0.8, 0.3);
+    }
+
+    commands.spawn(Camera2d);
+    commands.spawn((
+        Node {
+            width: Px(64.0),
+            height: Px(64.0),
+            align_self: AlignSelf::Center,
+            justify_self: JustifySelf::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.6, 0.6, 0.7)),
+        Tooltip::cursor(content),
+    ));
+}
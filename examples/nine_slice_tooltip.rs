@@ -0,0 +1,85 @@
+//! A demonstration of a primary tooltip with a nine-slice image background.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::ui::Val::*;
+use pyri_tooltip::prelude::*;
+
+/// The size of the panel texture (in pixels).
+const PANEL_SIZE: u32 = 24;
+
+/// The width of the panel texture's border (in pixels).
+const PANEL_BORDER: u32 = 6;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins((DefaultPlugins, TooltipPlugin::default()))
+        .add_systems(Startup, (style_primary_tooltip, spawn_scene))
+        .run()
+}
+
+fn style_primary_tooltip(
+    mut commands: Commands,
+    settings: Res<TooltipSettings>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let image = images.add(panel_image());
+    commands
+        .entity(settings.container)
+        .remove::<BackgroundColor>()
+        .insert(
+            ImageNode::new(image).with_mode(NodeImageMode::Sliced(TextureSlicer {
+                border: BorderRect::all(PANEL_BORDER as f32),
+                center_scale_mode: SliceScaleMode::Stretch,
+                sides_scale_mode: SliceScaleMode::Stretch,
+                max_corner_scale: 1.0,
+            })),
+        );
+}
+
+/// Generate a panel texture with a light border, a dark inner edge, and a translucent center.
+///
+/// A real game would load a panel texture from an asset file instead.
+fn panel_image() -> Image {
+    let mut data = Vec::with_capacity((PANEL_SIZE * PANEL_SIZE * 4) as usize);
+    for y in 0..PANEL_SIZE {
+        for x in 0..PANEL_SIZE {
+            let edge = x.min(y).min(PANEL_SIZE - 1 - x).min(PANEL_SIZE - 1 - y);
+            let pixel = match edge {
+                0 => [0, 0, 0, 0],
+                1 | 2 => [214, 178, 112, 255],
+                _ if edge < PANEL_BORDER => [72, 54, 32, 255],
+                _ => [27, 30, 31, 230],
+            };
+            data.extend_from_slice(&pixel);
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: PANEL_SIZE,
+            height: PANEL_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+fn spawn_scene(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    commands.spawn((
+        Node {
+            width: Px(64.0),
+            height: Px(64.0),
+            align_self: AlignSelf::Center,
+            justify_self: JustifySelf::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.6, 0.4, 0.2)),
+        Tooltip::cursor("A tooltip on a\nnine-slice panel"),
+    ));
+}
@@ -0,0 +1,89 @@
+//! A demonstration of tooltip text with a drop shadow over a busy background.
+
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use pyri_tooltip::prelude::*;
+
+/// The number of background tiles along each axis.
+const TILES: u32 = 24;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins((DefaultPlugins, TooltipPlugin::default()))
+        .add_systems(Startup, (remove_tooltip_background, spawn_scene))
+        .run()
+}
+
+fn remove_tooltip_background(mut commands: Commands, settings: Res<TooltipSettings>) {
+    commands
+        .entity(settings.container)
+        .remove::<BackgroundColor>();
+}
+
+fn spawn_scene(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    // Spawn a noisy background of tiles.
+    commands
+        .spawn(Node {
+            width: Percent(100.0),
+            height: Percent(100.0),
+            display: Display::Grid,
+            grid_template_columns: RepeatedGridTrack::flex(TILES as u16, 1.0),
+            grid_template_rows: RepeatedGridTrack::flex(TILES as u16, 1.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            for i in 0..TILES * TILES {
+                // A cheap hash to scatter the tile colors.
+                let hash = i.wrapping_mul(2_654_435_761);
+                let hue = (hash % 360) as f32;
+                let lightness = 0.3 + (hash >> 16) as f32 % 40.0 / 100.0;
+                parent.spawn((
+                    Node::default(),
+                    BackgroundColor(Color::hsl(hue, 0.8, lightness)),
+                    Pickable::IGNORE,
+                ));
+            }
+        });
+
+    // Spawn tooltip targets with and without a text shadow.
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Percent(100.0),
+                height: Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceEvenly,
+                ..default()
+            },
+            Pickable::IGNORE,
+        ))
+        .with_children(|parent| {
+            for (text, shadow) in [
+                (RichText::from("Hard to read"), None),
+                (
+                    RichText::from("Easy to read"),
+                    Some(TextShadow {
+                        offset: Vec2::splat(2.0),
+                        color: Color::BLACK,
+                    }),
+                ),
+            ] {
+                let text = match shadow {
+                    Some(shadow) => text.with_shadow(shadow),
+                    None => text,
+                };
+                parent.spawn((
+                    Node {
+                        width: Px(64.0),
+                        height: Px(64.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.1, 0.1, 0.1)),
+                    Tooltip::cursor(text),
+                ));
+            }
+        });
+}
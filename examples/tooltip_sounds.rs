@@ -0,0 +1,35 @@
+//! A demonstration of tooltips that play sounds when shown and hidden.
+//!
+//! This expects Ogg Vorbis sound files at `assets/sounds/tooltip_show.ogg` and
+//! `assets/sounds/tooltip_hide.ogg`.
+
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use pyri_tooltip::prelude::*;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins((DefaultPlugins, TooltipPlugin::default()))
+        .add_systems(Startup, spawn_scene)
+        .run()
+}
+
+fn spawn_scene(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let show_sound = asset_server.load("sounds/tooltip_show.ogg");
+    let hide_sound = asset_server.load("sounds/tooltip_hide.ogg");
+
+    commands.spawn(Camera2d);
+    commands.spawn((
+        Node {
+            width: Px(64.0),
+            height: Px(64.0),
+            align_self: AlignSelf::Center,
+            justify_self: JustifySelf::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.4, 0.2, 0.6)),
+        Tooltip::cursor("Listen closely")
+            .with_show_sound(show_sound)
+            .with_hide_sound(hide_sound),
+    ));
+}
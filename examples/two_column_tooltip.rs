@@ -0,0 +1,54 @@
+//! A demonstration of a tabular tooltip using named text slots in the primary tooltip.
+
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use pyri_tooltip::prelude::*;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins((DefaultPlugins, TooltipPlugin::default()))
+        .add_systems(Startup, (spawn_text_slots, spawn_scene))
+        .run()
+}
+
+fn spawn_text_slots(mut commands: Commands, settings: Res<TooltipSettings>) {
+    commands.entity(settings.container).insert(Node {
+        position_type: PositionType::Absolute,
+        column_gap: Px(16.0),
+        padding: UiRect::all(Px(8.0)),
+        ..default()
+    });
+    commands.spawn((
+        Name::new("Labels"),
+        TooltipTextSlot("label".to_string()),
+        ChildOf(settings.container),
+        Pickable::IGNORE,
+    ));
+    commands.spawn((
+        Name::new("Values"),
+        TooltipTextSlot("value".to_string()),
+        ChildOf(settings.container),
+        Pickable::IGNORE,
+    ));
+}
+
+fn spawn_scene(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    commands.spawn((
+        Node {
+            width: Px(64.0),
+            height: Px(64.0),
+            align_self: AlignSelf::Center,
+            justify_self: JustifySelf::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.6, 0.4, 0.2)),
+        Tooltip::cursor(TooltipContent::slots([
+            ("label", RichText::from("Damage\nSpeed\nWeight")),
+            (
+                "value",
+                RichText::from("12\n3.5\n8 kg").with_justify(JustifyText::Right),
+            ),
+        ])),
+    ));
+}
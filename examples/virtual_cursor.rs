@@ -0,0 +1,102 @@
+//! A demonstration of tooltips driven by a gamepad-controlled virtual cursor.
+
+use bevy::prelude::*;
+use bevy::ui::Val::*;
+use bevy::window::PrimaryWindow;
+use pyri_tooltip::prelude::*;
+
+/// The speed of the virtual cursor (in logical pixels per second).
+const CURSOR_SPEED: f32 = 600.0;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins((DefaultPlugins, TooltipPlugin::default()))
+        .add_systems(Startup, spawn_scene)
+        .add_systems(PreUpdate, move_virtual_cursor)
+        .add_systems(Update, draw_virtual_cursor)
+        .run()
+}
+
+#[derive(Component)]
+struct CursorIndicator;
+
+fn spawn_scene(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    commands
+        .spawn(Node {
+            width: Percent(100.0),
+            height: Percent(100.0),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::SpaceEvenly,
+            ..default()
+        })
+        .with_children(|parent| {
+            for (color, text) in [
+                (Color::srgb(0.8, 0.2, 0.2), "Move the left stick over me"),
+                (
+                    Color::srgb(0.2, 0.6, 0.2),
+                    "Tooltips follow the virtual cursor",
+                ),
+            ] {
+                parent.spawn((
+                    Node {
+                        width: Px(64.0),
+                        height: Px(64.0),
+                        ..default()
+                    },
+                    BackgroundColor(color),
+                    Tooltip::cursor(text),
+                ));
+            }
+        });
+    commands.spawn((
+        Name::new("CursorIndicator"),
+        Node {
+            position_type: PositionType::Absolute,
+            width: Px(8.0),
+            height: Px(8.0),
+            ..default()
+        },
+        BackgroundColor(Color::WHITE),
+        GlobalZIndex(1000),
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        CursorIndicator,
+    ));
+}
+
+fn move_virtual_cursor(
+    time: Res<Time>,
+    gamepad_query: Query<&Gamepad>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut virtual_cursor: ResMut<VirtualCursor>,
+) {
+    let Ok(window) = window_query.single() else {
+        return;
+    };
+    let Some(gamepad) = gamepad_query.iter().next() else {
+        return;
+    };
+
+    let stick = gamepad.left_stick();
+    let position = virtual_cursor.0.unwrap_or(window.size() / 2.0);
+    virtual_cursor.0 = Some(
+        (position + Vec2::new(stick.x, -stick.y) * CURSOR_SPEED * time.delta_secs())
+            .clamp(Vec2::ZERO, window.size()),
+    );
+}
+
+fn draw_virtual_cursor(
+    virtual_cursor: Res<VirtualCursor>,
+    mut indicator_query: Query<(&mut Node, &mut Visibility), With<CursorIndicator>>,
+) {
+    for (mut node, mut visibility) in &mut indicator_query {
+        let Some(position) = virtual_cursor.0 else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        *visibility = Visibility::Inherited;
+        node.left = Px(position.x - 4.0);
+        node.top = Px(position.y - 4.0);
+    }
+}
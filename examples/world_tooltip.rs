@@ -0,0 +1,40 @@
+//! A demonstration of a tooltip anchored to a moving world position.
+
+use bevy::prelude::*;
+use pyri_tooltip::prelude::*;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins((DefaultPlugins, TooltipPlugin::default()))
+        .add_systems(Startup, spawn_scene)
+        .add_systems(Update, move_world_tooltip)
+        .run()
+}
+
+fn spawn_scene(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    // Mark the world origin for reference.
+    commands.spawn(Sprite {
+        color: Color::WHITE,
+        custom_size: Some(Vec2::splat(8.0)),
+        ..default()
+    });
+}
+
+fn move_world_tooltip(
+    time: Res<Time>,
+    camera_query: Query<Entity, With<Camera2d>>,
+    mut world_tooltip: ResMut<WorldTooltip>,
+) {
+    let Ok(camera) = camera_query.single() else {
+        return;
+    };
+    let angle = time.elapsed_secs();
+    let position = 200.0 * Vec3::new(angle.cos(), angle.sin(), 0.0);
+    world_tooltip.show(
+        position,
+        camera,
+        Tooltip::cursor(format!("({:.0}, {:.0})", position.x, position.y))
+            .with_placement(TooltipPlacement::CURSOR_CENTERED),
+    );
+}
@@ -0,0 +1,56 @@
+use bevy_app::{App, PreUpdate};
+use bevy_asset::Handle;
+use bevy_audio::{AudioPlayer, AudioSource, PlaybackSettings};
+use bevy_ecs::{
+    entity::Entity,
+    name::Name,
+    schedule::IntoScheduleConfigs as _,
+    system::{Commands, Local, Res},
+};
+
+use crate::{
+    TooltipSystems,
+    context::{TooltipContext, TooltipState},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        PreUpdate,
+        play_tooltip_sounds.in_set(TooltipSystems::Content),
+    );
+}
+
+fn play_tooltip_sounds(
+    mut commands: Commands,
+    ctx: Res<TooltipContext>,
+    mut shown: Local<Option<(Entity, Option<Handle<AudioSource>>)>>,
+) {
+    let mut play = |sound: Handle<AudioSource>| {
+        commands.spawn((
+            Name::new("TooltipSound"),
+            AudioPlayer(sound),
+            PlaybackSettings::DESPAWN,
+        ));
+    };
+
+    // Play the hide sound of the previously shown tooltip.
+    let active = (matches!(ctx.state, TooltipState::Active) && !ctx.world).then_some(ctx.target);
+    if shown
+        .as_ref()
+        .is_some_and(|&(entity, _)| Some(entity) != active)
+        && let Some((_, hide_sound)) = shown.take()
+        && let Some(sound) = hide_sound
+    {
+        play(sound);
+    }
+
+    // Play the show sound of the newly shown tooltip, and remember its hide sound for later.
+    if shown.is_none()
+        && let Some(entity) = active
+    {
+        *shown = Some((entity, ctx.tooltip.hide_sound.clone()));
+        if let Some(sound) = ctx.tooltip.show_sound.clone() {
+            play(sound);
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use alloc::string::{String, ToString as _};
+
+#[cfg(feature = "accessibility")]
+use bevy_a11y::AccessibilityNode;
+use bevy_app::{App, PreUpdate};
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    name::Name,
+    query::{Changed, Or, With},
+    schedule::IntoScheduleConfigs as _,
+    system::{Commands, Query},
+};
+
+use crate::{Tooltip, TooltipContent, TooltipSystems};
+
+pub(super) fn plugin(app: &mut App) {
+    #[cfg(feature = "bevy_reflect")]
+    app.register_type::<AutoTooltip>();
+    app.add_systems(
+        PreUpdate,
+        generate_auto_tooltips::<Name>.before(TooltipSystems::Detection),
+    );
+    #[cfg(feature = "accessibility")]
+    app.add_systems(
+        PreUpdate,
+        generate_auto_tooltips::<AccessibilityNode>
+            .after(generate_auto_tooltips::<Name>)
+            .before(TooltipSystems::Detection),
+    );
+}
+
+/// A marker [`Component`] for entities whose [`Tooltip`] should be generated from a
+/// [`TooltipText`] component (e.g. [`Name`]).
+///
+/// This is intended for rapid prototyping and debugging.
+#[derive(Component, Copy, Clone, Default, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Component)
+)]
+pub struct AutoTooltip;
+
+/// A [`Component`] that can generate text for an [`AutoTooltip`].
+///
+/// [`Name`] is supported out of the box, as well as `AccessibilityNode` with the `accessibility`
+/// feature. If an entity has more than one, the most recently changed one is used. To support a
+/// custom component `T`, add [`generate_auto_tooltips::<T>`](generate_auto_tooltips) to the
+/// `PreUpdate` schedule before [`TooltipSystems::Detection`].
+pub trait TooltipText: Component {
+    /// The text to display in the tooltip, or `None` if there should be no tooltip.
+    fn tooltip_text(&self) -> Option<String>;
+}
+
+impl TooltipText for Name {
+    fn tooltip_text(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+/// A system that inserts or refreshes the [`Tooltip`] of each [`AutoTooltip`] entity from its
+/// `T` component.
+///
+/// A new tooltip uses [`Tooltip::cursor`]. An existing tooltip only has its content replaced, or is
+/// removed if there is no [text](TooltipText::tooltip_text).
+pub fn generate_auto_tooltips<T: TooltipText>(
+    mut commands: Commands,
+    mut auto_query: Query<
+        (Entity, &T, Option<&mut Tooltip>),
+        (With<AutoTooltip>, Or<(Changed<T>, Changed<AutoTooltip>)>),
+    >,
+) {
+    for (entity, text, tooltip) in &mut auto_query {
+        let Some(text) = text.tooltip_text() else {
+            if tooltip.is_some() {
+                commands.entity(entity).remove::<Tooltip>();
+            }
+            continue;
+        };
+        let content = TooltipContent::from(text);
+        match tooltip {
+            Some(mut tooltip) => tooltip.content = content,
+            None => {
+                commands.entity(entity).insert(Tooltip::cursor(content));
+            }
+        }
+    }
+}
+
+/// Use the accessible description, or the label if there is no description, so the tooltip stays
+/// in sync with the text read by screen readers. A node with neither has no tooltip.
+#[cfg(feature = "accessibility")]
+impl TooltipText for AccessibilityNode {
+    fn tooltip_text(&self) -> Option<String> {
+        Some(self.description().or(self.label())?.to_string())
+    }
+}
+
+#[cfg(all(test, feature = "accessibility"))]
+mod tests {
+    use bevy_ecs::{system::RunSystemOnce as _, world::World};
+
+    use super::*;
+
+    #[test]
+    fn accessibility_node_tooltip_uses_description() {
+        let mut world = World::new();
+        let mut node = AccessibilityNode(Default::default());
+        node.set_label("Save");
+        node.set_description("Save the current file");
+        let described = world.spawn((AutoTooltip, node)).id();
+        let unlabeled = world
+            .spawn((AutoTooltip, AccessibilityNode(Default::default())))
+            .id();
+        world
+            .run_system_once(generate_auto_tooltips::<AccessibilityNode>)
+            .unwrap();
+
+        let tooltip = world.get::<Tooltip>(described).unwrap();
+        assert!(matches!(
+            &tooltip.content,
+            TooltipContent::Primary(text) if text.sections[0].value == "Save the current file",
+        ));
+        assert!(world.get::<Tooltip>(unlabeled).is_none());
+
+        // Clearing the label and description removes the tooltip.
+        let mut node = world.get_mut::<AccessibilityNode>(described).unwrap();
+        node.clear_label();
+        node.clear_description();
+        world
+            .run_system_once(generate_auto_tooltips::<AccessibilityNode>)
+            .unwrap();
+        assert!(world.get::<Tooltip>(described).is_none());
+    }
+}
@@ -0,0 +1,158 @@
+use bevy_app::{App, PreUpdate};
+use bevy_color::{Alpha as _, Color};
+use bevy_ecs::{
+    change_detection::DetectChangesMut as _,
+    component::Component,
+    entity::Entity,
+    name::Name,
+    query::{With, Without},
+    schedule::IntoScheduleConfigs as _,
+    system::{Commands, Local, Query, Res},
+};
+use bevy_picking::Pickable;
+use bevy_time::Time;
+use bevy_ui::{BackgroundColor, GlobalZIndex, Node, PositionType, Val};
+
+use crate::{
+    TooltipSettings, TooltipSystems,
+    context::{TooltipContext, TooltipState},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(PreUpdate, update_backdrop.after(TooltipSystems::Content));
+}
+
+/// A full-screen backdrop that dims the rest of the UI while a tooltip is active.
+///
+/// The backdrop is displayed just below the [`GlobalZIndex`] of the displayed tooltip entity.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct TooltipBackdrop {
+    /// The color of the backdrop when fully visible.
+    pub color: Color,
+    /// The duration of the fade in / out (in milliseconds).
+    pub fade: u16,
+}
+
+impl Default for TooltipBackdrop {
+    fn default() -> Self {
+        Self {
+            color: Color::srgba(0.0, 0.0, 0.0, 0.5),
+            fade: 150,
+        }
+    }
+}
+
+/// A marker component for the backdrop entity.
+#[derive(Component)]
+struct Backdrop;
+
+fn update_backdrop(
+    mut commands: Commands,
+    mut backdrop_entity: Local<Option<Entity>>,
+    ctx: Res<TooltipContext>,
+    primary: Res<TooltipSettings>,
+    time: Res<Time>,
+    mut backdrop_query: Query<(&mut BackgroundColor, &mut GlobalZIndex), With<Backdrop>>,
+    z_index_query: Query<&GlobalZIndex, Without<Backdrop>>,
+) {
+    let Some(backdrop) = primary.backdrop else {
+        if let Some(entity) = backdrop_entity.take() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    // Display the backdrop just below the displayed tooltip entity, if any.
+    let z_index = ctx
+        .displayed_content()
+        .map(|content| content.entity(&primary))
+        .map(|entity| {
+            GlobalZIndex(
+                z_index_query
+                    .get(entity)
+                    .map_or(0, |z| z.0)
+                    .saturating_sub(1),
+            )
+        });
+
+    // Spawn the backdrop entity if it doesn't exist yet.
+    let Some((mut color, mut backdrop_z_index)) =
+        backdrop_entity.and_then(|entity| backdrop_query.get_mut(entity).ok())
+    else {
+        *backdrop_entity = Some(
+            commands
+                .spawn((
+                    Name::new("TooltipBackdrop"),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Vw(100.0),
+                        height: Val::Vh(100.0),
+                        ..Default::default()
+                    },
+                    BackgroundColor(backdrop.color.with_alpha(0.0)),
+                    z_index.unwrap_or_default(),
+                    Backdrop,
+                    // Prevent backdrop from interfering with picking detection
+                    Pickable::IGNORE,
+                ))
+                .id(),
+        );
+        return;
+    };
+
+    if let Some(z_index) = z_index {
+        backdrop_z_index.set_if_neq(z_index);
+    }
+
+    // Fade toward the target alpha.
+    let max_alpha = backdrop.color.alpha();
+    let target_alpha = if primary.enabled && matches!(ctx.state, TooltipState::Active) {
+        max_alpha
+    } else {
+        0.0
+    };
+    let step = if backdrop.fade == 0 {
+        f32::INFINITY
+    } else {
+        max_alpha * time.delta().as_millis() as f32 / backdrop.fade as f32
+    };
+    let alpha = color.0.alpha();
+    let alpha = if alpha < target_alpha {
+        (alpha + step).min(target_alpha)
+    } else {
+        (alpha - step).max(target_alpha)
+    };
+    color.set_if_neq(BackgroundColor(backdrop.color.with_alpha(alpha)));
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{schedule::Schedule, world::World};
+
+    use super::*;
+    use crate::Tooltip;
+
+    #[test]
+    fn backdrop_is_below_displayed_tooltip() {
+        let mut world = World::new();
+        let mut settings =
+            TooltipSettings::new(&mut world, Entity::PLACEHOLDER, Entity::PLACEHOLDER, true);
+        settings.backdrop = Some(TooltipBackdrop::default());
+        world.insert_resource(settings);
+        world.init_resource::<TooltipContext>();
+        world.init_resource::<Time>();
+        let custom = world.spawn(GlobalZIndex(500)).id();
+        let mut ctx = world.resource_mut::<TooltipContext>();
+        ctx.state = TooltipState::Active;
+        ctx.tooltip = Tooltip::cursor(custom);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_backdrop);
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+
+        let mut backdrop_query = world.query_filtered::<&GlobalZIndex, With<Backdrop>>();
+        assert_eq!(backdrop_query.single(&world).unwrap().0, 499);
+    }
+}
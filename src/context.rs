@@ -1,27 +1,58 @@
+use alloc::{borrow::Cow, collections::BTreeSet, string::String, vec::Vec};
+#[cfg(not(feature = "presets"))]
+use core::marker::PhantomData;
+use core::time::Duration;
+
 use bevy_app::{App, PreUpdate};
+use bevy_asset::Assets;
 #[cfg(feature = "bevy_reflect")]
-use bevy_ecs::reflect::ReflectResource;
+use bevy_ecs::reflect::{ReflectComponent, ReflectResource};
 use bevy_ecs::{
+    change_detection::DetectChangesMut as _,
+    component::Component,
     entity::Entity,
-    event::{Event, EventReader, EventWriter},
-    query::With,
+    event::{Event, EventCursor, EventReader, EventWriter, Events},
+    hierarchy::{ChildOf, Children},
+    name::Name,
+    query::{Added, Changed, Has, With, Without},
     resource::Resource,
     schedule::{IntoScheduleConfigs as _, common_conditions::on_event},
-    system::{Query, Res, ResMut},
+    system::{Commands, Local, Query, Res, ResMut, SystemParam},
+    world::World,
 };
-use bevy_math::Vec2;
+use bevy_image::{Image, TextureAtlasLayout};
+use bevy_input::{ButtonInput, mouse::MouseButton};
+use bevy_math::{Dir2, Rect, Vec2};
 use bevy_render::{
     camera::{Camera, RenderTarget},
+    mesh::Mesh2d,
+    primitives::Aabb,
     view::Visibility,
 };
-use bevy_time::Time;
+use bevy_sprite::Sprite;
+use bevy_text::{TextColor, TextSpan};
+use bevy_time::{Real, Time};
+use bevy_ui::{ComputedNode, GlobalZIndex, Node, Val};
 // UI picking is now handled through the unified picking system
 use bevy_window::{PrimaryWindow, Window, WindowRef};
 // Add these imports for sprite support and picking:
-use crate::{Tooltip, TooltipContent, TooltipSettings, TooltipSystems, rich_text::RichText};
-use bevy_picking::events::{Out, Over, Pointer};
+use crate::{
+    DelayMode, DismissDistance, ReactivatePolicy, SuppressedTooltipGroups, TimeSource, Tooltip,
+    TooltipContent, TooltipLayerBoost, TooltipMaxWidth, TooltipPassthrough, TooltipSettings,
+    TooltipSuppressSubtree, TooltipSystems, TooltipTextSlot,
+    forced::forced_tooltip_inactive,
+    rich_text::{InputGlyphResolver, RichText},
+    virtual_cursor::{VIRTUAL_POINTER_ID, VirtualCursor},
+    world::world_tooltip_inactive,
+};
+#[cfg(feature = "presets")]
+use crate::{TooltipPresetSet, TooltipPresets};
+use bevy_picking::{
+    events::{Out, Over, Pointer},
+    pointer::PointerId,
+};
 // Sprite import no longer needed - unified picking handles all entity types
-use bevy_transform::components::Transform;
+use bevy_transform::components::GlobalTransform;
 use tiny_bail::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
@@ -30,16 +61,41 @@ pub(super) fn plugin(app: &mut App) {
     app.init_resource::<TooltipContext>();
     app.add_event::<HideTooltip>();
     app.add_event::<ShowTooltip>();
+    app.add_event::<UpdateTooltipSpan>();
+    app.add_event::<TooltipWarm>();
+    app.add_event::<TooltipHidden>();
+    app.add_event::<RefreshTooltip>();
+    #[cfg(feature = "bevy_reflect")]
+    app.register_type::<TooltipWarmable>();
+    app.add_systems(
+        PreUpdate,
+        (
+            track_spawned_tooltips,
+            update_tooltip_eligibility.run_if(has_eligibility_candidates),
+            update_distance_dismiss_suppression.run_if(has_distance_dismiss_condition),
+            update_tooltip_context
+                .run_if(world_tooltip_inactive)
+                .run_if(forced_tooltip_inactive),
+            refresh_tooltip.run_if(on_event::<RefreshTooltip>),
+            warm_tooltips.run_if(|primary: Res<TooltipSettings>| primary.warm_distance.is_some()),
+        )
+            .chain()
+            .in_set(TooltipSystems::Detection),
+    );
     app.add_systems(
         PreUpdate,
         (
-            update_tooltip_context,
             hide_tooltip.run_if(on_event::<HideTooltip>),
             show_tooltip.run_if(on_event::<ShowTooltip>),
+            update_tooltip_span.run_if(on_event::<UpdateTooltipSpan>),
         )
             .chain()
             .in_set(TooltipSystems::Content),
     );
+    app.add_systems(
+        PreUpdate,
+        report_tooltip_hidden.in_set(TooltipSystems::Content),
+    );
 }
 
 /// A [`Resource`] that contains the current values in use by the tooltip system.
@@ -55,21 +111,85 @@ pub(crate) struct TooltipContext {
     /// The current or previous target entity being interacted with.
     pub(crate) target: Entity,
     /// The remaining duration of the current activation delay or transfer timeout (in milliseconds).
-    timer: u16,
+    pub(crate) timer: u16,
+    /// The full duration of the current activation delay, as chosen on entering the target entity
+    /// (in milliseconds).
+    base_delay: u16,
     /// The current cursor position or activation point.
     pub(crate) cursor_pos: Vec2,
+    /// The squared dismissal distance, resolved against the current target's size.
+    dismiss_distance_sq: f32,
+    /// The number of logical pixels per world unit at the target entity's position.
+    pixels_per_unit: f32,
+    /// Whether the tooltip is still active during its hide delay after losing its target entity.
+    pub(crate) lingering: bool,
+    /// The remaining duration of the current hide delay (in milliseconds).
+    hide_timer: u16,
+    /// How long the current tooltip has been active.
+    active_time: Duration,
+    /// The cursor position as of the previous update.
+    pub(crate) last_cursor_pos: Vec2,
+    /// Whether the cursor was in a window as of the previous update.
+    cursor_tracked: bool,
+    /// The current cursor speed (in logical pixels per second).
+    cursor_speed: f32,
+    /// The direction of the cursor's last movement, if it has moved.
+    cursor_direction: Option<Dir2>,
     /// The current tooltip parameters.
     pub(crate) tooltip: Tooltip,
+    /// Whether the current tooltip is a [`WorldTooltip`](crate::WorldTooltip).
+    pub(crate) world: bool,
+    /// The current target entity and newly hovered entities whose [`Tooltip::enabled_when`]
+    /// condition is false or that are in a [suppressed subtree](TooltipSuppressSubtree), as of
+    /// the last evaluation.
+    ineligible: BTreeSet<Entity>,
+    /// Whether the current tooltip's [`Tooltip::suppress_distance_dismiss_when`] condition was true
+    /// as of its last evaluation.
+    distance_dismiss_suppressed: bool,
+    /// The entities with a newly added [`Tooltip`] that should activate immediately if hovered
+    /// before the cursor moves.
+    spawned: BTreeSet<Entity>,
+    /// The most recent target entities whose tooltips were shown, and when they were last shown.
+    recently_shown: Vec<(Entity, Duration)>,
 }
 
+/// The maximum number of entries in [`TooltipContext::recently_shown`].
+const RECENTLY_SHOWN_CAPACITY: usize = 32;
+
 impl Default for TooltipContext {
     fn default() -> Self {
         Self {
             state: TooltipState::Inactive,
             target: Entity::PLACEHOLDER,
             timer: 0,
+            base_delay: 0,
+            lingering: false,
+            hide_timer: 0,
+            active_time: Duration::ZERO,
             cursor_pos: Vec2::ZERO,
+            dismiss_distance_sq: f32::INFINITY,
+            pixels_per_unit: 1.0,
+            last_cursor_pos: Vec2::ZERO,
+            cursor_tracked: false,
+            cursor_speed: 0.0,
+            cursor_direction: None,
             tooltip: Tooltip::cursor(Entity::PLACEHOLDER),
+            world: false,
+            ineligible: BTreeSet::new(),
+            distance_dismiss_suppressed: false,
+            spawned: BTreeSet::new(),
+            recently_shown: Vec::new(),
+        }
+    }
+}
+
+impl TooltipContext {
+    /// The tooltip content that should currently be displayed, if any.
+    pub(crate) fn displayed_content(&self) -> Option<&TooltipContent> {
+        match self.state {
+            TooltipState::Active => Some(&self.tooltip.content),
+            TooltipState::Delayed => self.tooltip.delayed_content.as_ref(),
+            _ => None,
         }
     }
 }
@@ -79,95 +199,464 @@ fn should_activate_immediately(
     tooltip: &Tooltip,
     ctx: &TooltipContext,
     target_entity: Entity,
+    boosted: bool,
+    delay_scale: f32,
 ) -> bool {
-    tooltip.activation.delay == 0
-        || (matches!(ctx.state, TooltipState::Inactive)
+    scale_delay(tooltip.activation.delay, delay_scale) == 0
+        || (!tooltip.transfer.no_transfer_in
+            && matches!(ctx.state, TooltipState::Inactive)
             && ctx.timer > 0
-            && ctx.tooltip.transfer.layer >= tooltip.transfer.layer
+            && (boosted || ctx.tooltip.transfer.layer >= tooltip.transfer.layer)
             && (matches!((ctx.tooltip.transfer.group, tooltip.transfer.group), (Some(x), Some(y)) if x == y)
                 || ctx.target == target_entity))
 }
 
+// Helper function to check whether a tooltip's hold button (if any) is held
+fn is_hold_satisfied(tooltip: &Tooltip, mouse_input: Option<&ButtonInput<MouseButton>>) -> bool {
+    tooltip
+        .activation
+        .hold_button
+        .is_none_or(|button| mouse_input.is_some_and(|input| input.pressed(button)))
+}
+
+// Helper function to check whether the cursor entered in the tooltip's enter direction (if any)
+fn is_enter_direction_satisfied(tooltip: &Tooltip, ctx: &TooltipContext) -> bool {
+    tooltip.activation.enter_direction.is_none_or(|direction| {
+        ctx.cursor_direction
+            .is_some_and(|cursor| cursor.dot(*direction) >= core::f32::consts::FRAC_1_SQRT_2)
+    })
+}
+
+// Helper function to transition to inactive when there is no longer a target entity
+fn release_target(ctx: &mut TooltipContext) {
+    let transfer = &ctx.tooltip.transfer;
+    let starts_transfer = match ctx.state {
+        TooltipState::Active => true,
+        TooltipState::Dismissed(_) => transfer.dismiss_starts_transfer,
+        // Leaving a strict target entity during its activation delay cancels activation.
+        TooltipState::Delayed if ctx.tooltip.activation.strict_continuous => false,
+        _ => !transfer.from_active,
+    };
+    ctx.timer = if starts_transfer { transfer.timeout } else { 0 };
+    ctx.state = TooltipState::Inactive;
+    ctx.lingering = false;
+}
+
+/// The dismissal distance of a [target-relative](DismissDistance::RelativeToTarget) tooltip whose
+/// target entity's size is unknown (in logical pixels).
+const UNKNOWN_SIZE_DISMISS_DISTANCE: f32 = 64.0;
+
+/// A [`SystemParam`] for looking up target entities and measuring their size.
+#[derive(SystemParam)]
+pub(crate) struct TargetQuery<'w, 's> {
+    query: Query<
+        'w,
+        's,
+        (
+            &'static GlobalTransform,
+            Option<&'static ComputedNode>,
+            Option<&'static Sprite>,
+        ),
+    >,
+    mesh_query: Query<'w, 's, &'static Aabb, With<Mesh2d>>,
+    images: Option<Res<'w, Assets<Image>>>,
+    atlas_layouts: Option<Res<'w, Assets<TextureAtlasLayout>>>,
+}
+
+impl TargetQuery<'_, '_> {
+    /// Get the components of a target entity.
+    pub(crate) fn get(
+        &self,
+        entity: Entity,
+    ) -> Option<(&GlobalTransform, Option<&ComputedNode>, Option<&Sprite>)> {
+        self.query.get(entity).ok()
+    }
+
+    /// Get the size of a target entity, or `None` if it's unknown (e.g. its image hasn't loaded).
+    ///
+    /// This is in logical pixels for a UI node, or in world units for a sprite or [`Mesh2d`].
+    pub(crate) fn size(&self, entity: Entity) -> Option<Vec2> {
+        match self.get(entity)? {
+            (_, Some(computed), _) => Some(computed.size() * computed.inverse_scale_factor()),
+            (gt, None, _) => Some(self.local_rect(entity)?.size() * gt.scale().truncate().abs()),
+        }
+    }
+
+    /// Get the local size of a sprite before scaling, or `None` if it's unknown.
+    pub(crate) fn sprite_size(&self, sprite: &Sprite) -> Option<Vec2> {
+        if let Some(size) = sprite.custom_size {
+            return Some(size);
+        }
+        if let Some(rect) = sprite.rect {
+            return Some(rect.size());
+        }
+        if let Some(atlas) = &sprite.texture_atlas {
+            let rect = atlas.texture_rect(self.atlas_layouts.as_deref()?)?;
+            return Some(rect.size().as_vec2());
+        }
+        Some(self.images.as_deref()?.get(&sprite.image)?.size_f32())
+    }
+
+    /// Get the local bounds of a sprite or [`Mesh2d`] target entity before transforming, or `None`
+    /// if they're unknown.
+    ///
+    /// For a sprite, this is offset from the origin by its [`Anchor`](bevy_sprite::Anchor). For a
+    /// [`Mesh2d`], this is its [`Aabb`], which is only available after its mesh has loaded.
+    pub(crate) fn local_rect(&self, entity: Entity) -> Option<Rect> {
+        if let (_, _, Some(sprite)) = self.get(entity)? {
+            let size = self.sprite_size(sprite)?;
+            return Some(Rect::from_center_size(-sprite.anchor.as_vec() * size, size));
+        }
+        let aabb = self.mesh_query.get(entity).ok()?;
+        Some(Rect::from_center_half_size(
+            aabb.center.truncate(),
+            aabb.half_extents.truncate(),
+        ))
+    }
+}
+
+/// A [`SystemParam`] for resolving the [preset](Tooltip::preset) of a [`Tooltip`].
+///
+/// Without the `presets` feature, tooltips are left as is.
+#[derive(SystemParam)]
+pub(crate) struct PresetQuery<'w> {
+    #[cfg(feature = "presets")]
+    presets: Option<Res<'w, TooltipPresets>>,
+    #[cfg(feature = "presets")]
+    preset_sets: Option<Res<'w, Assets<TooltipPresetSet>>>,
+    #[cfg(not(feature = "presets"))]
+    _marker: PhantomData<&'w ()>,
+}
+
+impl PresetQuery<'_> {
+    /// Apply the preset of a [`Tooltip`], if any.
+    pub(crate) fn resolve<'a>(&self, tooltip: &'a Tooltip) -> Cow<'a, Tooltip> {
+        #[cfg(feature = "presets")]
+        {
+            let preset_set = self
+                .presets
+                .as_ref()
+                .zip(self.preset_sets.as_ref())
+                .and_then(|(presets, preset_sets)| preset_sets.get(&presets.0));
+            crate::preset::resolve_preset(preset_set, tooltip)
+        }
+        #[cfg(not(feature = "presets"))]
+        Cow::Borrowed(tooltip)
+    }
+}
+
+// Helper function to resolve the squared dismissal distance of a tooltip for its target entity
+fn dismiss_distance_sq(tooltip: &Tooltip, target_size: Option<Vec2>, pixels_per_unit: f32) -> f32 {
+    let distance = match (tooltip.dismissal.on_distance, target_size) {
+        (DismissDistance::RelativeToTarget(_), None) => UNKNOWN_SIZE_DISMISS_DISTANCE,
+        (distance, size) => distance.resolve_scaled(size.unwrap_or_default(), pixels_per_unit),
+    };
+    distance.powi(2)
+}
+
+// Helper function to scale an activation delay by the global delay scale
+fn scale_delay(delay: u16, delay_scale: f32) -> u16 {
+    (delay as f32 * delay_scale)
+        .round()
+        .clamp(0.0, u16::MAX as f32) as u16
+}
+
 // Helper function to apply tooltip transition
 fn apply_tooltip_transition(
     ctx: &mut TooltipContext,
     entity: Entity,
     tooltip: &Tooltip,
+    target_size: Option<Vec2>,
     activate_immediately: bool,
+    now: Duration,
+    delay_scale: f32,
 ) {
+    // Preserve the activation delay timer when re-entering the same delayed target entity.
+    let reentered = ctx.target == entity
+        && matches!(ctx.state, TooltipState::Delayed)
+        && !tooltip.activation.strict_continuous;
     ctx.state = if activate_immediately {
         TooltipState::Active
     } else {
         TooltipState::Delayed
     };
+    // Don't carry over the old target entity's distance dismissal suppression.
+    if ctx.target != entity {
+        ctx.distance_dismiss_suppressed = false;
+    }
     ctx.target = entity;
-    ctx.timer = tooltip.activation.delay;
+    if !reentered {
+        // Use the reduced delay if the target entity's tooltip was shown recently.
+        let activation = &tooltip.activation;
+        let recent = activation.recent_window > 0
+            && ctx.recently_shown.iter().any(|&(shown, shown_at)| {
+                shown == entity
+                    && now.saturating_sub(shown_at)
+                        <= Duration::from_millis(activation.recent_window as u64)
+            });
+        let delay = if recent {
+            activation.recent_delay
+        } else {
+            activation.delay
+        };
+        ctx.base_delay = scale_delay(delay, delay_scale);
+        ctx.timer = ctx.base_delay;
+    }
+    ctx.lingering = false;
     ctx.tooltip = tooltip.clone();
-    ctx.tooltip.dismissal.on_distance *= ctx.tooltip.dismissal.on_distance;
+    ctx.dismiss_distance_sq = dismiss_distance_sq(tooltip, target_size, ctx.pixels_per_unit);
+}
+
+// Helper function to check whether an entity or any of its ancestors suppresses its subtree or
+// no longer exists (e.g. the target entity was despawned while its tooltip was active)
+fn has_suppressing_ancestor(world: &World, entity: Entity) -> bool {
+    let mut entity = Some(entity);
+    while let Some(current) = entity {
+        let Ok(current) = world.get_entity(current) else {
+            return true;
+        };
+        if current.contains::<TooltipSuppressSubtree>() {
+            return true;
+        }
+        entity = current.get::<ChildOf>().map(ChildOf::parent);
+    }
+    false
+}
+
+// Helper function to check whether a target entity's tooltip is eligible for activation
+fn is_eligible(world: &World, entity: Entity) -> bool {
+    world
+        .get::<Tooltip>(entity)
+        .is_none_or(|tooltip| tooltip.enabled_when.is_none_or(|enabled| enabled(world)))
+        && !has_suppressing_ancestor(world, entity)
 }
 
-fn update_tooltip_context(
+fn track_spawned_tooltips(
+    mut ctx: ResMut<TooltipContext>,
+    added_query: Query<(Entity, &Tooltip), Added<Tooltip>>,
+) {
+    let spawned = added_query
+        .iter()
+        .filter(|(_, tooltip)| tooltip.activation.immediate_on_spawn)
+        .map(|(entity, _)| entity);
+    ctx.spawned.extend(spawned);
+}
+
+fn has_eligibility_candidates(
+    ctx: Res<TooltipContext>,
+    mut picking_over_events: EventReader<Pointer<Over>>,
+) -> bool {
+    let hovered = picking_over_events.read().count() > 0;
+    hovered || !matches!(ctx.state, TooltipState::Inactive)
+}
+
+fn update_tooltip_eligibility(
+    world: &mut World,
+    mut picking_over_cursor: Local<EventCursor<Pointer<Over>>>,
+) {
+    // Only evaluate the current target entity and the newly hovered entities.
+    let ctx = world.resource::<TooltipContext>();
+    let target = (!matches!(ctx.state, TooltipState::Inactive)).then_some(ctx.target);
+    let picking_over_events = world.resource::<Events<Pointer<Over>>>();
+    let ineligible = target
+        .into_iter()
+        .chain(
+            picking_over_cursor
+                .read(picking_over_events)
+                .map(|event| event.target),
+        )
+        .filter(|&entity| !is_eligible(world, entity))
+        .collect();
+    world.resource_mut::<TooltipContext>().ineligible = ineligible;
+}
+
+fn has_distance_dismiss_condition(ctx: Res<TooltipContext>) -> bool {
+    matches!(ctx.state, TooltipState::Active)
+        && ctx.tooltip.suppress_distance_dismiss_when.is_some()
+}
+
+fn update_distance_dismiss_suppression(world: &mut World) {
+    let ctx = world.resource::<TooltipContext>();
+    let suppressed = ctx
+        .tooltip
+        .suppress_distance_dismiss_when
+        .is_some_and(|suppress| suppress(world));
+    world
+        .resource_mut::<TooltipContext>()
+        .distance_dismiss_suppressed = suppressed;
+}
+
+pub(crate) fn update_tooltip_context(
     mut ctx: ResMut<TooltipContext>,
     mut hide_tooltip: EventWriter<HideTooltip>,
     mut show_tooltip: EventWriter<ShowTooltip>,
     primary: Res<TooltipSettings>,
-    time: Res<Time>,
+    (suppressed_groups, virtual_cursor, presets): (
+        Res<SuppressedTooltipGroups>,
+        Res<VirtualCursor>,
+        PresetQuery,
+    ),
+    (time, real_time): (Res<Time>, Res<Time<Real>>),
+    mouse_input: Option<Res<ButtonInput<MouseButton>>>,
     primary_window_query: Query<Entity, With<PrimaryWindow>>,
     window_query: Query<&Window>,
-    camera_query: Query<(&Camera, &Transform)>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
     // Use unified picking events for both UI and sprite tooltips
     mut picking_over_events: EventReader<Pointer<Over>>,
     mut picking_out_events: EventReader<Pointer<Out>>,
-    tooltip_query: Query<&Tooltip>,
+    tooltip_query: Query<(&Tooltip, Has<TooltipLayerBoost>)>,
+    targets: TargetQuery,
+    name_query: Query<Option<&Name>>,
+    passthrough_query: Query<(), With<TooltipPassthrough>>,
 ) {
-    let old_active = matches!(ctx.state, TooltipState::Active);
     let old_target = ctx.target;
-    let old_entity = match ctx.tooltip.content {
-        TooltipContent::Primary(_) => primary.container,
-        TooltipContent::Custom(id) => id,
-    };
+    let old_entity = ctx
+        .displayed_content()
+        .map(|content| content.entity(&primary));
 
     // TODO: Reconsider whether this is the right way to detect cursor movement.
     // Detect cursor movement.
-    for camera in &camera_query {
-        let RenderTarget::Window(window) = camera.0.target else {
-            continue;
-        };
-        let window = match window {
-            WindowRef::Primary => cq!(primary_window_query.single()),
-            WindowRef::Entity(id) => id,
+    let mut cursor_moved = false;
+    // Use the focused window if it reports a cursor position, or any other window that does
+    // (e.g. when the cursor is over an unfocused window on another monitor).
+    let cursor = camera_query
+        .iter()
+        .filter_map(|(camera, camera_gt)| {
+            let RenderTarget::Window(window) = camera.target else {
+                return None;
+            };
+            let window = match window {
+                WindowRef::Primary => primary_window_query.single().ok()?,
+                WindowRef::Entity(id) => id,
+            };
+            let is_primary = primary_window_query.contains(window);
+            let window = window_query.get(window).ok()?;
+            let cursor_pos = match virtual_cursor.0 {
+                Some(cursor_pos) => is_primary.then_some(cursor_pos)?,
+                None => window.cursor_position()?,
+            };
+            Some((camera, camera_gt, window.focused, cursor_pos))
+        })
+        .min_by_key(|&(_, _, focused, _)| !focused);
+    if let Some((camera, camera_gt, _, cursor_pos)) = cursor {
+        // Measure the camera's scale at a non-UI target entity's position.
+        ctx.pixels_per_unit = match targets.get(ctx.target) {
+            Some((gt, None, _)) => {
+                let pos = gt.translation();
+                camera
+                    .world_to_viewport(camera_gt, pos)
+                    .and_then(|a| {
+                        let b = camera.world_to_viewport(camera_gt, pos + *camera_gt.right())?;
+                        Ok(a.distance(b))
+                    })
+                    .unwrap_or(1.0)
+            }
+            _ => 1.0,
         };
-        let window = c!(window_query.get(window));
-        cq!(window.focused);
-        let cursor_pos = cq!(window.cursor_position());
+
+        // Measure cursor speed and direction, skipping the first sample after the cursor enters a
+        // window because there is no previous position to measure from.
+        let dt = time.delta_secs();
+        if !ctx.cursor_tracked {
+            ctx.cursor_speed = 0.0;
+        } else if dt > 0.0 {
+            ctx.cursor_speed = ctx.last_cursor_pos.distance(cursor_pos) / dt;
+        }
+        cursor_moved = ctx.last_cursor_pos != cursor_pos;
+        if ctx.cursor_tracked
+            && let Ok(direction) = Dir2::new(cursor_pos - ctx.last_cursor_pos)
+        {
+            ctx.cursor_direction = Some(direction);
+        }
+        let last_cursor_pos = ctx.last_cursor_pos;
+        ctx.last_cursor_pos = cursor_pos;
+        ctx.cursor_tracked = true;
 
         // Reset activation delay on cursor move.
         if ctx.cursor_pos != cursor_pos
             && matches!(ctx.state, TooltipState::Delayed)
-            && ctx.tooltip.activation.reset_delay_on_cursor_move
+            && ctx.tooltip.activation.delay_mode == DelayMode::Reset
         {
-            ctx.timer = ctx.tooltip.activation.delay;
+            ctx.timer = ctx.base_delay;
         }
 
         // Dismiss tooltip if cursor has left the activation radius.
+        // When dismissal is directional, only dismiss while moving away from the target's center.
+        let moving_away = match targets.get(ctx.target) {
+            Some((gt, Some(computed), _)) if ctx.tooltip.dismissal.directional => {
+                let center = gt.translation().truncate() * computed.inverse_scale_factor();
+                cursor_pos.distance_squared(center) > last_cursor_pos.distance_squared(center)
+            }
+            _ => true,
+        };
         if matches!(ctx.state, TooltipState::Active)
-            && ctx.cursor_pos.distance_squared(cursor_pos) > ctx.tooltip.dismissal.on_distance
+            && ctx.cursor_pos.distance_squared(cursor_pos) > ctx.dismiss_distance_sq
+            && moving_away
+            && !(ctx.distance_dismiss_suppressed
+                && ctx.tooltip.suppress_distance_dismiss_when.is_some())
         {
-            ctx.state = TooltipState::Dismissed;
+            ctx.state = TooltipState::Dismissed(DismissReason::Distance);
         }
 
         // Update cursor position.
         if !matches!(ctx.state, TooltipState::Active) {
             ctx.cursor_pos = cursor_pos;
         }
+    } else {
+        ctx.cursor_tracked = false;
+    }
+
+    // Measure elapsed time for the current tooltip's timers using its time source.
+    let delta = match ctx.tooltip.time_source {
+        TimeSource::Virtual => time.delta(),
+        TimeSource::Real => real_time.delta(),
+    };
+    let delta_ms = delta.as_millis() as u16;
 
-        break;
+    // Entities spawned before the cursor moved are no longer under the cursor from the start.
+    if cursor_moved {
+        ctx.spawned.clear();
+    }
+
+    // Dismiss tooltip on left click, or reactivate it according to its reactivate policy.
+    if matches!(ctx.state, TooltipState::Active)
+        && ctx.tooltip.dismissal.on_click
+        && mouse_input
+            .as_deref()
+            .is_some_and(|input| input.just_pressed(MouseButton::Left))
+    {
+        ctx.state = TooltipState::Dismissed(DismissReason::Click);
+        ctx.timer = match ctx.tooltip.dismissal.reactivate {
+            ReactivatePolicy::Cooldown(cooldown) => cooldown,
+            _ => 0,
+        };
+    } else if ctx.state == TooltipState::Dismissed(DismissReason::Click) {
+        let reactivate = match ctx.tooltip.dismissal.reactivate {
+            ReactivatePolicy::OnReenter => false,
+            ReactivatePolicy::Immediate => cursor_moved,
+            ReactivatePolicy::Cooldown(_) => {
+                ctx.timer = ctx.timer.saturating_sub(delta_ms);
+                ctx.timer == 0
+            }
+        };
+        if reactivate {
+            ctx.state = TooltipState::Active;
+        }
     }
 
     // Tick timer for transfer timeout / activation delay.
-    if matches!(ctx.state, TooltipState::Inactive | TooltipState::Delayed) {
-        ctx.timer = ctx.timer.saturating_sub(time.delta().as_millis() as u16);
-        if matches!(ctx.state, TooltipState::Delayed) && ctx.timer == 0 {
+    // The activation delay is held while the cursor is moving too fast, or paused while the
+    // cursor is moving at all.
+    let too_fast = ctx.cursor_speed > primary.max_activation_speed;
+    let paused = cursor_moved && ctx.tooltip.activation.delay_mode == DelayMode::Pause;
+    if matches!(ctx.state, TooltipState::Inactive)
+        || (matches!(ctx.state, TooltipState::Delayed) && !too_fast && !paused)
+    {
+        ctx.timer = ctx.timer.saturating_sub(delta_ms);
+        if matches!(ctx.state, TooltipState::Delayed)
+            && ctx.timer == 0
+            && is_hold_satisfied(&ctx.tooltip, mouse_input.as_deref())
+        {
             ctx.state = TooltipState::Active;
         }
     }
@@ -175,67 +664,190 @@ fn update_tooltip_context(
     // Use unified picking events for both UI and sprite tooltips
     let mut found_target = false;
 
+    // Tick timer for hide delay.
+    if ctx.lingering {
+        ctx.hide_timer = ctx.hide_timer.saturating_sub(delta_ms);
+    }
+
     // First, check if we're currently hovering something and should maintain that state
-    if !matches!(ctx.state, TooltipState::Inactive) {
-        if let Ok(tooltip) = tooltip_query.get(ctx.target) {
+    // Ineligible target entities are skipped, as if they had no tooltip.
+    if !matches!(ctx.state, TooltipState::Inactive)
+        && !ctx.lingering
+        && !ctx.ineligible.contains(&ctx.target)
+    {
+        if let Ok((tooltip, _)) = tooltip_query.get(ctx.target) {
             // We're still hovering an entity with a tooltip - maintain the state
-            ctx.tooltip = tooltip.clone();
-            ctx.tooltip.dismissal.on_distance *= ctx.tooltip.dismissal.on_distance;
+            let tooltip = presets.resolve(tooltip);
+            ctx.dismiss_distance_sq =
+                dismiss_distance_sq(&tooltip, targets.size(ctx.target), ctx.pixels_per_unit);
+            found_target = true;
+
+            // Dismiss tooltip if its group has been suppressed.
+            if suppressed_groups.contains(&tooltip) {
+                ctx.state = TooltipState::Dismissed(DismissReason::Suppressed);
+            }
+            ctx.tooltip = tooltip.into_owned();
+        } else if primary.fallback.is_some() && name_query.contains(ctx.target) {
+            // We're still hovering an entity with a fallback tooltip - maintain the state
             found_target = true;
         }
     }
 
     // Handle out events to stop hovering
+    // Only listen to the virtual pointer while the virtual cursor is in use.
+    let from_cursor =
+        |pointer_id: PointerId| virtual_cursor.0.is_none() || pointer_id == VIRTUAL_POINTER_ID;
+
     for out_event in picking_out_events.read() {
-        let entity = out_event.target;
-        if tooltip_query.get(entity).is_ok() {
-            // If we were hovering this entity and now we're not, clear the target
-            if ctx.target == entity && !matches!(ctx.state, TooltipState::Inactive) {
-                found_target = false; // Force transition to inactive
-                break;
-            }
+        // If we were hovering this entity and now we're not, clear the target
+        if from_cursor(out_event.pointer_id)
+            && ctx.target == out_event.target
+            && !matches!(ctx.state, TooltipState::Inactive)
+        {
+            found_target = false; // Force transition to inactive
+            break;
         }
     }
 
     // Handle over events from picking system (only if we're not already hovering something)
     if !found_target {
+        let mut fallback_target = None;
         for over_event in picking_over_events.read() {
+            cq!(from_cursor(over_event.pointer_id));
             let entity = over_event.target;
-            if let Ok(tooltip) = tooltip_query.get(entity) {
-                // Switch to the new target entity.
-                let activate_immediately = should_activate_immediately(tooltip, &ctx, entity);
-                apply_tooltip_transition(&mut ctx, entity, tooltip, activate_immediately);
+            if let Ok((tooltip, boosted)) = tooltip_query.get(entity) {
+                let tooltip = &*presets.resolve(tooltip);
+                if suppressed_groups.contains(tooltip)
+                    || ctx.ineligible.contains(&entity)
+                    || !is_enter_direction_satisfied(tooltip, &ctx)
+                {
+                    continue;
+                }
+
+                // Stop lingering if the cursor has returned to the target entity.
+                if ctx.lingering {
+                    if ctx.target == entity {
+                        ctx.lingering = false;
+                        found_target = true;
+                        break;
+                    }
+                    release_target(&mut ctx);
+                }
+
+                // Switch to the new target entity, unless the cursor is moving too fast.
+                let spawned = ctx.spawned.remove(&entity);
+                let activate_immediately = !too_fast
+                    && is_hold_satisfied(tooltip, mouse_input.as_deref())
+                    && (spawned
+                        || should_activate_immediately(
+                            tooltip,
+                            &ctx,
+                            entity,
+                            boosted,
+                            primary.delay_scale,
+                        ));
+                apply_tooltip_transition(
+                    &mut ctx,
+                    entity,
+                    tooltip,
+                    targets.size(entity),
+                    activate_immediately,
+                    time.elapsed(),
+                    primary.delay_scale,
+                );
                 found_target = true;
                 break;
             }
+            if !passthrough_query.contains(entity) {
+                fallback_target = fallback_target.or(Some(entity));
+            }
         }
+
+        // Fall back to a generated tooltip for the topmost hovered entity.
+        if !found_target
+            && let Some(fallback) = primary.fallback
+            && let Some(entity) = fallback_target
+            && let Ok(name) = name_query.get(entity)
+        {
+            let tooltip = fallback.tooltip(entity, name);
+            let activate_immediately = !too_fast
+                && should_activate_immediately(&tooltip, &ctx, entity, false, primary.delay_scale);
+            apply_tooltip_transition(
+                &mut ctx,
+                entity,
+                &tooltip,
+                targets.size(entity),
+                activate_immediately,
+                time.elapsed(),
+                primary.delay_scale,
+            );
+            found_target = true;
+        }
+    }
+
+    // Deactivate tooltip while its hold button is released.
+    if matches!(ctx.state, TooltipState::Active)
+        && !is_hold_satisfied(&ctx.tooltip, mouse_input.as_deref())
+    {
+        ctx.state = TooltipState::Delayed;
+        ctx.timer = 0;
     }
 
     // There is no longer a target entity.
     if !found_target && !matches!(ctx.state, TooltipState::Inactive) {
-        ctx.timer =
-            if matches!(ctx.state, TooltipState::Active) || !ctx.tooltip.transfer.from_active {
-                ctx.tooltip.transfer.timeout
-            } else {
-                0
-            };
-        ctx.state = TooltipState::Inactive;
+        if matches!(ctx.state, TooltipState::Active)
+            && !ctx.lingering
+            && ctx.tooltip.activation.hide_delay > 0
+        {
+            // Linger for the hide delay before hiding the tooltip.
+            ctx.lingering = true;
+            ctx.hide_timer = ctx.tooltip.activation.hide_delay;
+        } else if !(ctx.lingering
+            && ctx.hide_timer > 0
+            && matches!(ctx.state, TooltipState::Active))
+        {
+            release_target(&mut ctx);
+        }
+    }
+
+    // Dismiss tooltip if it has been active for longer than the max lifetime.
+    if !matches!(ctx.state, TooltipState::Active) || old_target != ctx.target {
+        ctx.active_time = Duration::ZERO;
+    }
+    if matches!(ctx.state, TooltipState::Active) {
+        // Remember when each target entity's tooltip was last shown.
+        let target = ctx.target;
+        ctx.recently_shown.retain(|&(shown, _)| shown != target);
+        if ctx.recently_shown.len() >= RECENTLY_SHOWN_CAPACITY {
+            ctx.recently_shown.remove(0);
+        }
+        ctx.recently_shown.push((target, time.elapsed()));
+
+        ctx.active_time += delta;
+        if primary
+            .max_lifetime
+            .is_some_and(|max_lifetime| ctx.active_time > max_lifetime)
+        {
+            ctx.state = TooltipState::Dismissed(DismissReason::Lifetime);
+        }
     }
 
     // Update tooltip if it has a target, or was activated, dismissed, or changed targets.
-    let new_active = matches!(ctx.state, TooltipState::Active);
-    if old_active != new_active || old_target != ctx.target || found_target {
-        if old_active {
-            hide_tooltip.write(HideTooltip { entity: old_entity });
+    let new_entity = ctx
+        .displayed_content()
+        .map(|content| content.entity(&primary));
+    if old_entity.is_some() != new_entity.is_some() || old_target != ctx.target || found_target {
+        if let Some(entity) = old_entity {
+            hide_tooltip.write(HideTooltip { entity });
         }
-        if new_active {
+        if new_entity.is_some() {
             show_tooltip.write(ShowTooltip);
         }
     }
 }
 
 /// The current state of the tooltip system.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 pub(crate) enum TooltipState {
     /// There is no target entity being interacted with, and no active tooltip.
@@ -245,14 +857,28 @@ pub(crate) enum TooltipState {
     /// A target entity is being hovered, and its tooltip is active.
     Active,
     /// A target entity is being interacted with, but its tooltip has been dismissed.
-    Dismissed,
+    Dismissed(DismissReason),
+}
+
+/// The reason a tooltip was dismissed.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub(crate) enum DismissReason {
+    /// The cursor left the dismissal radius.
+    Distance,
+    /// The tooltip's group was suppressed.
+    Suppressed,
+    /// The tooltip exceeded its max lifetime.
+    Lifetime,
+    /// The tooltip was clicked.
+    Click,
 }
 
 /// A buffered event sent when a tooltip should be hidden.
 #[derive(Event)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
-struct HideTooltip {
-    entity: Entity,
+pub(crate) struct HideTooltip {
+    pub(crate) entity: Entity,
 }
 
 fn hide_tooltip(
@@ -267,22 +893,1260 @@ fn hide_tooltip(
 /// A buffered event sent when a tooltip should be shown.
 #[derive(Event)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
-struct ShowTooltip;
+pub(crate) struct ShowTooltip;
 
 fn show_tooltip(
-    mut ctx: ResMut<TooltipContext>,
+    mut commands: Commands,
+    ctx: Res<TooltipContext>,
+    tooltip_query: Query<&Tooltip>,
+    presets: PresetQuery,
     primary: Res<TooltipSettings>,
-    mut text_query: Query<&mut RichText>,
+    mut text_query: Query<&mut RichText, Without<TooltipTextSlot>>,
+    mut slot_query: Query<(&TooltipTextSlot, &mut RichText)>,
     mut visibility_query: Query<&mut Visibility>,
+    color_query: Query<&TextColor>,
+    glyph_resolver: Option<Res<InputGlyphResolver>>,
+    mut node_query: Query<&mut Node>,
+    targets: TargetQuery,
+    mut base_max_width: Local<Option<(Entity, Val)>>,
+    z_index_query: Query<&GlobalZIndex>,
+    mut base_z_index: Local<Option<(Entity, Option<GlobalZIndex>)>>,
+    mut last_primary_text: Local<Option<(Entity, RichText)>>,
 ) {
-    let entity = match ctx.tooltip.content {
-        TooltipContent::Primary(ref mut text) => {
+    // Resolve the primary tooltip's max width, restoring the container's own max width if unset.
+    if let Ok(mut node) = node_query.get_mut(primary.container) {
+        let base = match *base_max_width {
+            Some((container, base)) if container == primary.container => base,
+            _ => node.max_width,
+        };
+        *base_max_width = Some((primary.container, base));
+        // Keep the container's own max width if a target-relative max width can't be resolved.
+        let max_width = ctx.tooltip.max_width.and_then(|max_width| match max_width {
+            TooltipMaxWidth::Pixels(width) => Some(width),
+            TooltipMaxWidth::RelativeToTarget(_) => {
+                Some(max_width.resolve(targets.size(ctx.target)?.x))
+            }
+        });
+        node.max_width = max_width.map_or(base, Val::Px);
+    }
+
+    let target_color = color_query
+        .get(ctx.target)
+        .ok()
+        .filter(|_| ctx.tooltip.inherit_text_color);
+    // Read the content from the live `Tooltip` if possible, so that back-to-back activations
+    // always display up-to-date content.
+    let tooltip = presets.resolve(match tooltip_query.get(ctx.target) {
+        Ok(tooltip) if !ctx.world => tooltip,
+        _ => &ctx.tooltip,
+    });
+    let content = match ctx.state {
+        TooltipState::Active => &tooltip.content,
+        TooltipState::Delayed => r!(tooltip.delayed_content.as_ref()),
+        _ => return,
+    };
+    let prepare_text = |text: &mut RichText| {
+        prepare_tooltip_text(
+            text,
+            &tooltip,
+            primary.scale,
+            glyph_resolver.as_deref(),
+            target_color,
+        );
+    };
+    let entity = match content {
+        TooltipContent::Primary(text) => {
+            // Skip unchanged text to avoid a redundant re-layout, and to keep the font size of
+            // auto-fit text that has already been shrunk.
             if let Ok(mut primary_text) = text_query.get_mut(primary.text) {
-                *primary_text = core::mem::take(text);
+                let mut text = text.clone();
+                prepare_text(&mut text);
+                if last_primary_text.as_ref() != Some(&(primary.text, text.clone())) {
+                    primary_text.set_if_neq(text.clone());
+                    *last_primary_text = Some((primary.text, text));
+                }
+            }
+            for (_, mut slot_text) in &mut slot_query {
+                slot_text.set_if_neq(RichText::default());
             }
             primary.container
         }
-        TooltipContent::Custom(id) => id,
+        TooltipContent::Slots(slots) => {
+            if let Ok(mut primary_text) = text_query.get_mut(primary.text) {
+                primary_text.set_if_neq(RichText::default());
+            }
+            *last_primary_text = None;
+            for (slot, mut slot_text) in &mut slot_query {
+                let mut text = slots
+                    .iter()
+                    .find(|(name, _)| *name == slot.0)
+                    .map(|(_, text)| text.clone())
+                    .unwrap_or_default();
+                prepare_text(&mut text);
+                slot_text.set_if_neq(text);
+            }
+            primary.container
+        }
+        &TooltipContent::Custom(id) => id,
     };
+
+    // Apply the z-index, restoring the primary container's own z-index if unset.
+    let z_index = tooltip.z_index.map(GlobalZIndex);
+    let z_index = if entity == primary.container {
+        let base = match *base_z_index {
+            Some((container, base)) if container == primary.container => base,
+            _ => z_index_query.get(entity).ok().copied(),
+        };
+        *base_z_index = Some((primary.container, base));
+        z_index.or(base)
+    } else {
+        z_index
+    };
+    match z_index {
+        Some(z_index) if z_index_query.get(entity).ok() != Some(&z_index) => {
+            commands.entity(entity).insert(z_index);
+        }
+        None if entity == primary.container && z_index_query.contains(entity) => {
+            commands.entity(entity).remove::<GlobalZIndex>();
+        }
+        _ => {}
+    }
+
     *r!(visibility_query.get_mut(entity)) = Visibility::Visible;
 }
+
+// Helper function to prepare tooltip text for display in the primary tooltip
+fn prepare_tooltip_text(
+    text: &mut RichText,
+    tooltip: &Tooltip,
+    scale: f32,
+    glyph_resolver: Option<&InputGlyphResolver>,
+    target_color: Option<&TextColor>,
+) {
+    if let Some(resolver) = glyph_resolver {
+        resolver.resolve(text);
+    }
+    if let Some(max_lines) = tooltip.max_lines {
+        text.truncate_lines(max_lines);
+    }
+    if let Some(shadow) = &mut text.shadow {
+        shadow.offset *= scale;
+    }
+    for section in &mut text.sections {
+        if let Some(autofit) = tooltip.autofit {
+            section.style.font_size = autofit.max_font;
+        }
+        section.style.font_size *= scale;
+        if let Some(color) = target_color {
+            section.style.color = color.0;
+        }
+    }
+}
+
+/// A buffered event that updates the text of a single section of the active primary tooltip.
+///
+/// Unlike replacing the entire [`RichText`], this only touches the changed text span entities. The
+/// target's [`Tooltip`] is updated as well without being marked as changed, so the change persists
+/// without refreshing the tooltip.
+#[derive(Event, Clone, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct UpdateTooltipSpan {
+    /// The index of the [`TextSection`](crate::TextSection) in the target's [`Tooltip`] content to
+    /// update.
+    pub index: usize,
+    /// The new text value.
+    pub value: String,
+}
+
+fn update_tooltip_span(
+    mut update_tooltip_span: EventReader<UpdateTooltipSpan>,
+    ctx: Res<TooltipContext>,
+    primary: Res<TooltipSettings>,
+    mut tooltip_query: Query<&mut Tooltip>,
+    presets: PresetQuery,
+    color_query: Query<&TextColor>,
+    glyph_resolver: Option<Res<InputGlyphResolver>>,
+    mut text_query: Query<(&mut RichText, Option<&Children>)>,
+    mut span_query: Query<&mut TextSpan>,
+) {
+    rq!(matches!(ctx.state, TooltipState::Active) && !ctx.world);
+
+    // Update the target's tooltip without triggering a refresh.
+    let mut tooltip = r!(tooltip_query.get_mut(ctx.target));
+    let tooltip = tooltip.bypass_change_detection();
+    let TooltipContent::Primary(text) = &mut tooltip.content else {
+        return;
+    };
+    for event in update_tooltip_span.read() {
+        let section = c!(text.sections.get_mut(event.index));
+        section.value.clone_from(&event.value);
+    }
+
+    // Prepare the updated text the same way as `show_tooltip`, so that the sections line up.
+    let tooltip = presets.resolve(tooltip);
+    let TooltipContent::Primary(text) = &tooltip.content else {
+        return;
+    };
+    let mut prepared = text.clone();
+    let target_color = color_query
+        .get(ctx.target)
+        .ok()
+        .filter(|_| tooltip.inherit_text_color);
+    prepare_tooltip_text(
+        &mut prepared,
+        &tooltip,
+        primary.scale,
+        glyph_resolver.as_deref(),
+        target_color,
+    );
+
+    // Fall back to a full sync of the text spans if the number of sections has changed.
+    let (mut text, children) = r!(text_query.get_mut(primary.text));
+    if text.sections.len() != prepared.sections.len() {
+        *text = prepared;
+        return;
+    }
+
+    // Update the primary tooltip text and the changed text spans only.
+    let spans = children
+        .into_iter()
+        .flatten()
+        .copied()
+        .filter(|&child| span_query.contains(child))
+        .collect::<Vec<_>>();
+    for (i, (section, new_section)) in text
+        .bypass_change_detection()
+        .sections
+        .iter_mut()
+        .zip(prepared.sections)
+        .enumerate()
+    {
+        if section.value == new_section.value {
+            continue;
+        }
+        section.value = new_section.value;
+        if let Some(mut span) = spans.get(i).and_then(|&span| span_query.get_mut(span).ok()) {
+            span.0.clone_from(&section.value);
+        }
+    }
+}
+
+/// A buffered event sent when an active tooltip is hidden, reporting how long it was visible.
+///
+/// This can be used for analytics, such as finding tooltips that players never read. It's not
+/// sent for a [`WorldTooltip`](crate::WorldTooltip).
+#[derive(Event, Copy, Clone, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct TooltipHidden {
+    /// The target entity whose tooltip was hidden.
+    pub entity: Entity,
+    /// The duration the tooltip was active for.
+    ///
+    /// This is measured on the tooltip's [time source](crate::Tooltip::time_source).
+    pub visible: Duration,
+}
+
+fn report_tooltip_hidden(
+    mut tooltip_hidden: EventWriter<TooltipHidden>,
+    mut shown: Local<Option<(Entity, TimeSource, Duration)>>,
+    ctx: Res<TooltipContext>,
+    time: Res<Time>,
+    real_time: Res<Time<Real>>,
+) {
+    let elapsed = |time_source| match time_source {
+        TimeSource::Virtual => time.elapsed(),
+        TimeSource::Real => real_time.elapsed(),
+    };
+    let active = (matches!(ctx.state, TooltipState::Active) && !ctx.world).then_some(ctx.target);
+    if shown.is_some_and(|(entity, ..)| Some(entity) != active)
+        && let Some((entity, time_source, start)) = shown.take()
+    {
+        // Measure on the clock the tooltip was shown with, in case the new tooltip uses another.
+        tooltip_hidden.write(TooltipHidden {
+            entity,
+            visible: elapsed(time_source).saturating_sub(start),
+        });
+    }
+    if shown.is_none()
+        && let Some(entity) = active
+    {
+        let time_source = ctx.tooltip.time_source;
+        *shown = Some((entity, time_source, elapsed(time_source)));
+    }
+}
+
+/// A marker [`Component`] for tooltip target entities that should be warmed ahead of activation.
+///
+/// See [`TooltipSettings::warm_distance`] and [`TooltipWarm`].
+#[derive(Component, Copy, Clone, Default, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Component)
+)]
+pub struct TooltipWarmable;
+
+/// A buffered event sent when the cursor comes within [`TooltipSettings::warm_distance`] of a
+/// UI node with [`Tooltip`] and [`TooltipWarmable`].
+///
+/// This can be used to prepare heavy custom tooltip content (e.g. spawn its children or load
+/// its assets) before the tooltip is activated.
+#[derive(Event, Copy, Clone, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct TooltipWarm {
+    /// The target entity whose tooltip should be warmed.
+    pub entity: Entity,
+}
+
+fn warm_tooltips(
+    mut warm_tooltip: EventWriter<TooltipWarm>,
+    mut warmed: Local<BTreeSet<Entity>>,
+    ctx: Res<TooltipContext>,
+    primary: Res<TooltipSettings>,
+    warmable_query: Query<
+        (Entity, &GlobalTransform, &ComputedNode),
+        (With<Tooltip>, With<TooltipWarmable>),
+    >,
+) {
+    let warm_distance = rq!(primary.warm_distance);
+    for (entity, gt, computed) in &warmable_query {
+        // Measure the distance from the cursor to the target's rect (in logical pixels).
+        let scale = computed.inverse_scale_factor();
+        let center = gt.translation().truncate() * scale;
+        let half_size = computed.size() * scale / 2.0;
+        let distance = ((ctx.last_cursor_pos - center).abs() - half_size)
+            .max(Vec2::ZERO)
+            .length();
+
+        // Send the event once each time the cursor enters the warm distance.
+        if distance <= warm_distance {
+            if warmed.insert(entity) {
+                warm_tooltip.write(TooltipWarm { entity });
+            }
+        } else {
+            warmed.remove(&entity);
+        }
+    }
+}
+
+/// The relationship between an entity and the tooltip system in the current frame.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum TooltipRelation {
+    /// The entity is not the current target.
+    #[default]
+    None,
+    /// The entity is being hovered, but its tooltip is not active yet.
+    Delayed,
+    /// The entity is being hovered, and its tooltip is active.
+    Active,
+    /// The entity is being interacted with, but its tooltip has been dismissed.
+    Dismissed,
+}
+
+/// A [`SystemParam`] for inspecting the [`TooltipRelation`] of every entity with a [`Tooltip`].
+#[derive(SystemParam)]
+pub struct TooltipRelations<'w, 's> {
+    ctx: Res<'w, TooltipContext>,
+    primary: Res<'w, TooltipSettings>,
+    tooltip_query: Query<'w, 's, (Entity, &'static Tooltip)>,
+    text_query: Query<'w, 's, &'static RichText, Without<TooltipTextSlot>>,
+}
+
+impl TooltipRelations<'_, '_> {
+    /// Get the [`TooltipRelation`] of an entity.
+    pub fn get(&self, entity: Entity) -> TooltipRelation {
+        if self.ctx.world || self.ctx.target != entity {
+            return TooltipRelation::None;
+        }
+
+        match self.ctx.state {
+            TooltipState::Inactive => TooltipRelation::None,
+            TooltipState::Delayed => TooltipRelation::Delayed,
+            TooltipState::Active => TooltipRelation::Active,
+            TooltipState::Dismissed(_) => TooltipRelation::Dismissed,
+        }
+    }
+
+    /// Iterate over all entities with a [`Tooltip`] along with their [`TooltipRelation`].
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &Tooltip, TooltipRelation)> {
+        self.tooltip_query
+            .iter()
+            .map(|(entity, tooltip)| (entity, tooltip, self.get(entity)))
+    }
+
+    /// Get the [`TooltipContent`] that's currently displayed, if any.
+    ///
+    /// This includes the [delayed content](Tooltip::delayed_content) while the activation delay
+    /// is in progress.
+    pub fn displayed_content(&self) -> Option<&TooltipContent> {
+        self.ctx.displayed_content()
+    }
+
+    /// Get the plain text that's currently displayed in the primary tooltip, if any.
+    ///
+    /// This is read from the primary tooltip text entity, so it reflects the resolved text (e.g.
+    /// with [`InputGlyph`](crate::InputGlyph)s replaced). Returns `None` for custom tooltips and
+    /// [`TooltipContent::Slots`].
+    pub fn displayed_text(&self) -> Option<String> {
+        if !matches!(self.displayed_content()?, TooltipContent::Primary(_)) {
+            return None;
+        }
+
+        let text = self.text_query.get(self.primary.text).ok()?;
+        Some(
+            text.sections
+                .iter()
+                .map(|section| section.value.as_str())
+                .collect(),
+        )
+    }
+}
+
+/// A buffered event that refreshes the tooltip of a target entity if it's active or dismissed.
+///
+/// Tooltip content is always read from the target's live [`Tooltip`], so this is only needed to
+/// bring back a dismissed tooltip, or to apply changes to its other settings (e.g. placement)
+/// while it's shown. A tooltip dismissed because its group was suppressed is not refreshed.
+///
+/// See [`refresh_tooltips_on_change`] to send this event when a component changes.
+#[derive(Event, Copy, Clone, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct RefreshTooltip(pub Entity);
+
+/// A system that sends a [`RefreshTooltip`] event for each target entity whose `T` component
+/// changed.
+///
+/// To watch a component `T`, add [`refresh_tooltips_on_change::<T>`](refresh_tooltips_on_change)
+/// to the `PreUpdate` schedule before [`TooltipSystems::Detection`].
+pub fn refresh_tooltips_on_change<T: Component>(
+    mut refresh_tooltip: EventWriter<RefreshTooltip>,
+    changed_query: Query<Entity, (With<Tooltip>, Changed<T>)>,
+) {
+    refresh_tooltip.write_batch(changed_query.iter().map(RefreshTooltip));
+}
+
+fn refresh_tooltip(
+    mut refresh_tooltip: EventReader<RefreshTooltip>,
+    mut ctx: ResMut<TooltipContext>,
+    tooltip_query: Query<&Tooltip>,
+    presets: PresetQuery,
+    mut show_tooltip: EventWriter<ShowTooltip>,
+) {
+    let target = ctx.target;
+    let refreshed = refresh_tooltip.read().any(|event| event.0 == target);
+    refresh_tooltip.clear();
+    rq!(refreshed && !ctx.world);
+    rq!(matches!(
+        ctx.state,
+        TooltipState::Active | TooltipState::Dismissed(_)
+    ));
+    rq!(ctx.state != TooltipState::Dismissed(DismissReason::Suppressed));
+    let tooltip = r!(tooltip_query.get(target));
+
+    ctx.tooltip = presets.resolve(tooltip).into_owned();
+    ctx.state = TooltipState::Active;
+    show_tooltip.write(ShowTooltip);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        change_detection::DetectChanges as _, schedule::Schedule, system::RunSystemOnce as _,
+    };
+    use bevy_math::Vec3;
+    use bevy_picking::{backend::HitData, pointer::Location};
+    use bevy_render::camera::NormalizedRenderTarget;
+
+    use super::*;
+    use crate::{
+        SuppressedTooltipGroups, TextSection, TooltipActivation, TooltipDismissal, TooltipTransfer,
+    };
+
+    fn setup() -> World {
+        let mut world = World::new();
+        let settings =
+            TooltipSettings::new(&mut world, Entity::PLACEHOLDER, Entity::PLACEHOLDER, true);
+        world.insert_resource(settings);
+        world.init_resource::<TooltipContext>();
+        world.init_resource::<SuppressedTooltipGroups>();
+        world.init_resource::<VirtualCursor>();
+        world.init_resource::<Time>();
+        world.init_resource::<Time<Real>>();
+        world.init_resource::<Events<HideTooltip>>();
+        world.init_resource::<Events<ShowTooltip>>();
+        world.init_resource::<Events<Pointer<Over>>>();
+        world.init_resource::<Events<Pointer<Out>>>();
+        world.spawn((Window::default(), PrimaryWindow));
+        world.spawn((Camera::default(), GlobalTransform::default()));
+        world
+    }
+
+    // Get the pointer location at the given position in the primary window.
+    fn location(world: &mut World, cursor_pos: Vec2) -> Location {
+        let window_entity = world
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(world)
+            .unwrap();
+        Location {
+            target: NormalizedRenderTarget::Window(
+                WindowRef::Entity(window_entity).normalize(None).unwrap(),
+            ),
+            position: cursor_pos,
+        }
+    }
+
+    // Run the detection systems for one 16 ms frame with the cursor at the given position.
+    fn update(world: &mut World, cursor_pos: Vec2, hovered: Option<Entity>) {
+        let delta = Duration::from_millis(16);
+        world.resource_mut::<Time>().advance_by(delta);
+        world.resource_mut::<Time<Real>>().advance_by(delta);
+        let mut window = world
+            .query_filtered::<&mut Window, With<PrimaryWindow>>()
+            .single_mut(world)
+            .unwrap();
+        window.set_cursor_position(Some(cursor_pos));
+        if let Some(target) = hovered {
+            let location = location(world, cursor_pos);
+            let hit = HitData::new(Entity::PLACEHOLDER, 0.0, None, None);
+            world.send_event(Pointer::new(
+                PointerId::Mouse,
+                location,
+                target,
+                Over { hit },
+            ));
+        }
+
+        world.run_system_once(update_tooltip_eligibility).unwrap();
+        world.run_system_once(update_tooltip_context).unwrap();
+        world.resource_mut::<Events<Pointer<Over>>>().clear();
+        world.resource_mut::<Events<Pointer<Out>>>().clear();
+    }
+
+    // Run the detection systems for one frame in which the cursor leaves the target entity.
+    fn leave(world: &mut World, cursor_pos: Vec2, target: Entity) {
+        let location = location(world, cursor_pos);
+        let hit = HitData::new(Entity::PLACEHOLDER, 0.0, None, None);
+        world.send_event(Pointer::new(
+            PointerId::Mouse,
+            location,
+            target,
+            Out { hit },
+        ));
+        update(world, cursor_pos, None);
+    }
+
+    fn state(world: &World) -> TooltipState {
+        world.resource::<TooltipContext>().state
+    }
+
+    #[test]
+    fn despawning_target_releases_active_tooltip() {
+        let mut world = setup();
+        let target = world
+            .spawn(Tooltip::cursor("Hello, world!").with_activation(TooltipActivation::IMMEDIATE))
+            .id();
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        assert_eq!(state(&world), TooltipState::Active);
+
+        world.despawn(target);
+        update(&mut world, Vec2::new(100.0, 100.0), None);
+        assert_eq!(state(&world), TooltipState::Inactive);
+    }
+
+    #[test]
+    fn fast_cursor_holds_activation() {
+        let mut world = setup();
+        world.resource_mut::<TooltipSettings>().max_activation_speed = 1000.0;
+        let target = world
+            .spawn(Tooltip::cursor("Hello, world!").with_activation(TooltipActivation::IMMEDIATE))
+            .id();
+
+        // The first sample has no previous position, so it doesn't count as fast movement.
+        update(&mut world, Vec2::new(600.0, 400.0), None);
+        assert_eq!(world.resource::<TooltipContext>().cursor_speed, 0.0);
+
+        // Sweeping quickly over the target doesn't activate its tooltip.
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        assert_eq!(state(&world), TooltipState::Delayed);
+        update(&mut world, Vec2::new(200.0, 100.0), None);
+        assert_eq!(state(&world), TooltipState::Delayed);
+
+        // Slowing down activates it.
+        update(&mut world, Vec2::new(205.0, 100.0), None);
+        assert_eq!(state(&world), TooltipState::Active);
+    }
+
+    #[test]
+    fn cursor_entering_window_activates_immediately() {
+        let mut world = setup();
+        world.resource_mut::<TooltipSettings>().max_activation_speed = 1000.0;
+        let target = world
+            .spawn(Tooltip::cursor("Hello, world!").with_activation(TooltipActivation::IMMEDIATE))
+            .id();
+
+        update(&mut world, Vec2::new(600.0, 400.0), Some(target));
+        assert_eq!(state(&world), TooltipState::Active);
+    }
+
+    #[test]
+    fn only_left_click_dismisses_tooltip() {
+        let mut world = setup();
+        world.init_resource::<ButtonInput<MouseButton>>();
+        let target = world
+            .spawn(Tooltip::cursor("Hello, world!").with_activation(TooltipActivation::IMMEDIATE))
+            .id();
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        assert_eq!(state(&world), TooltipState::Active);
+
+        for button in [MouseButton::Right, MouseButton::Middle] {
+            world
+                .resource_mut::<ButtonInput<MouseButton>>()
+                .press(button);
+            update(&mut world, Vec2::new(100.0, 100.0), None);
+            world.resource_mut::<ButtonInput<MouseButton>>().clear();
+            assert_eq!(state(&world), TooltipState::Active);
+        }
+
+        world
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        update(&mut world, Vec2::new(100.0, 100.0), None);
+        assert_eq!(state(&world), TooltipState::Dismissed(DismissReason::Click));
+    }
+
+    #[test]
+    fn mesh2d_target_has_size() {
+        let mut world = setup();
+        let target = world
+            .spawn((
+                GlobalTransform::from_scale(Vec3::new(2.0, -3.0, 1.0)),
+                Mesh2d(Default::default()),
+                Aabb::from_min_max(Vec3::new(-10.0, -5.0, 0.0), Vec3::new(30.0, 15.0, 0.0)),
+            ))
+            .id();
+        let size = world
+            .run_system_once(move |targets: TargetQuery| {
+                (targets.local_rect(target), targets.size(target))
+            })
+            .unwrap();
+        assert_eq!(
+            size,
+            (
+                Some(Rect::new(-10.0, -5.0, 30.0, 15.0)),
+                Some(Vec2::new(80.0, 60.0)),
+            ),
+        );
+    }
+
+    #[test]
+    fn update_span_leaves_other_spans_unchanged() {
+        let mut world = setup();
+        world.init_resource::<Events<UpdateTooltipSpan>>();
+        let text = RichText::from_sections(["a", "b", "c"].map(TextSection::from));
+        let target = world.spawn(Tooltip::cursor(text.clone())).id();
+        let mut ctx = world.resource_mut::<TooltipContext>();
+        ctx.state = TooltipState::Active;
+        ctx.target = target;
+
+        // Display the text, as synced by `RichText`.
+        let primary_text = world.resource::<TooltipSettings>().text;
+        world
+            .entity_mut(primary_text)
+            .insert(text)
+            .with_children(|parent| {
+                for value in ["a", "b", "c"] {
+                    parent.spawn(TextSpan::new(value));
+                }
+            });
+        world.clear_trackers();
+
+        world.send_event(UpdateTooltipSpan {
+            index: 1,
+            value: "B".into(),
+        });
+        world.run_system_once(update_tooltip_span).unwrap();
+
+        let spans = world
+            .get::<Children>(primary_text)
+            .unwrap()
+            .iter()
+            .map(|&child| world.get::<TextSpan>(child).unwrap().0.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(spans, ["a", "B", "c"]);
+        let values = |text: &RichText| {
+            text.sections
+                .iter()
+                .map(|section| section.value.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            values(world.get::<RichText>(primary_text).unwrap()),
+            ["a", "B", "c"]
+        );
+        let tooltip = world.entity(target).get_ref::<Tooltip>().unwrap();
+        assert!(!tooltip.is_changed());
+        let TooltipContent::Primary(text) = &tooltip.content else {
+            unreachable!();
+        };
+        assert_eq!(values(text), ["a", "B", "c"]);
+    }
+
+    #[test]
+    fn reset_delay_restarts_on_cursor_move() {
+        let mut world = setup();
+        let activation = TooltipActivation {
+            delay_mode: DelayMode::Reset,
+            ..TooltipActivation::from(100)
+        };
+        let target = world
+            .spawn(Tooltip::cursor("Hello, world!").with_activation(activation))
+            .id();
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+
+        // Moving the cursor for longer than the delay keeps restarting it.
+        for x in 1..=10 {
+            update(&mut world, Vec2::new(100.0 + x as f32, 100.0), None);
+            assert_eq!(state(&world), TooltipState::Delayed);
+        }
+
+        // The rest of the delay restarted on the last move elapses once the cursor is still.
+        for _ in 0..5 {
+            update(&mut world, Vec2::new(110.0, 100.0), None);
+            assert_eq!(state(&world), TooltipState::Delayed);
+        }
+        update(&mut world, Vec2::new(110.0, 100.0), None);
+        assert_eq!(state(&world), TooltipState::Active);
+    }
+
+    #[test]
+    fn pause_delay_holds_on_cursor_move() {
+        let mut world = setup();
+        let activation = TooltipActivation {
+            delay_mode: DelayMode::Pause,
+            ..TooltipActivation::from(100)
+        };
+        let target = world
+            .spawn(Tooltip::cursor("Hello, world!").with_activation(activation))
+            .id();
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        for _ in 0..3 {
+            update(&mut world, Vec2::new(100.0, 100.0), None);
+        }
+        assert_eq!(world.resource::<TooltipContext>().timer, 100 - 3 * 16);
+
+        // Moving the cursor holds the delay.
+        for x in 1..=10 {
+            update(&mut world, Vec2::new(100.0 + x as f32, 100.0), None);
+            assert_eq!(state(&world), TooltipState::Delayed);
+        }
+        assert_eq!(world.resource::<TooltipContext>().timer, 100 - 3 * 16);
+
+        // The rest of the delay elapses once the cursor is still.
+        for _ in 0..3 {
+            update(&mut world, Vec2::new(110.0, 100.0), None);
+            assert_eq!(state(&world), TooltipState::Delayed);
+        }
+        update(&mut world, Vec2::new(110.0, 100.0), None);
+        assert_eq!(state(&world), TooltipState::Active);
+    }
+
+    #[test]
+    fn relative_dismiss_distance_scales_with_target() {
+        let mut world = setup();
+        let dismissal = TooltipDismissal {
+            on_distance: DismissDistance::RelativeToTarget(1.5),
+            ..TooltipDismissal::NONE
+        };
+        let mut spawn_target = |size: Vec2| {
+            world
+                .spawn((
+                    Sprite {
+                        custom_size: Some(size),
+                        ..Default::default()
+                    },
+                    Tooltip::cursor("Hello, world!")
+                        .with_activation(TooltipActivation::IMMEDIATE)
+                        .with_dismissal(dismissal),
+                ))
+                .id()
+        };
+        let small = spawn_target(Vec2::new(20.0, 10.0));
+        let large = spawn_target(Vec2::new(200.0, 100.0));
+
+        // The small target's tooltip is dismissed past 15 pixels.
+        update(&mut world, Vec2::new(100.0, 100.0), Some(small));
+        assert_eq!(
+            world.resource::<TooltipContext>().dismiss_distance_sq,
+            15.0 * 15.0
+        );
+        update(&mut world, Vec2::new(114.0, 100.0), None);
+        assert_eq!(state(&world), TooltipState::Active);
+        update(&mut world, Vec2::new(116.0, 100.0), None);
+        assert_eq!(
+            state(&world),
+            TooltipState::Dismissed(DismissReason::Distance)
+        );
+
+        // The large target's tooltip is dismissed past 150 pixels.
+        leave(&mut world, Vec2::new(300.0, 100.0), small);
+        update(&mut world, Vec2::new(300.0, 100.0), Some(large));
+        assert_eq!(state(&world), TooltipState::Active);
+        assert_eq!(
+            world.resource::<TooltipContext>().dismiss_distance_sq,
+            150.0 * 150.0
+        );
+        update(&mut world, Vec2::new(400.0, 100.0), None);
+        assert_eq!(state(&world), TooltipState::Active);
+        update(&mut world, Vec2::new(451.0, 100.0), None);
+        assert_eq!(
+            state(&world),
+            TooltipState::Dismissed(DismissReason::Distance)
+        );
+    }
+
+    #[test]
+    fn dismissal_starts_transfer_if_enabled() {
+        for dismiss_starts_transfer in [true, false] {
+            let mut world = setup();
+            world.init_resource::<ButtonInput<MouseButton>>();
+            let tooltip = Tooltip::cursor("Hello, world!")
+                .with_activation(100)
+                .with_dismissal(TooltipDismissal::ON_CLICK)
+                .with_transfer(TooltipTransfer {
+                    dismiss_starts_transfer,
+                    ..TooltipTransfer::SHORT
+                });
+            let a = world.spawn(tooltip.clone()).id();
+            let b = world.spawn(tooltip).id();
+
+            update(&mut world, Vec2::new(100.0, 100.0), Some(a));
+            for _ in 0..7 {
+                update(&mut world, Vec2::new(100.0, 100.0), None);
+            }
+            assert_eq!(state(&world), TooltipState::Active);
+
+            // Dismiss the tooltip and move to another target entity in the same transfer group.
+            world
+                .resource_mut::<ButtonInput<MouseButton>>()
+                .press(MouseButton::Left);
+            update(&mut world, Vec2::new(100.0, 100.0), None);
+            world.resource_mut::<ButtonInput<MouseButton>>().clear();
+            assert_eq!(state(&world), TooltipState::Dismissed(DismissReason::Click));
+            leave(&mut world, Vec2::new(200.0, 100.0), a);
+            update(&mut world, Vec2::new(200.0, 100.0), Some(b));
+
+            let expected = if dismiss_starts_transfer {
+                TooltipState::Active
+            } else {
+                TooltipState::Delayed
+            };
+            assert_eq!(state(&world), expected);
+        }
+    }
+
+    #[test]
+    fn max_lifetime_dismisses_until_reentry() {
+        let mut world = setup();
+        world.resource_mut::<TooltipSettings>().max_lifetime = Some(Duration::from_millis(100));
+        let target = world
+            .spawn(Tooltip::cursor("Hello, world!").with_activation(TooltipActivation::IMMEDIATE))
+            .id();
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        for _ in 0..5 {
+            update(&mut world, Vec2::new(100.0, 100.0), None);
+            assert_eq!(state(&world), TooltipState::Active);
+        }
+        update(&mut world, Vec2::new(100.0, 100.0), None);
+        assert_eq!(
+            state(&world),
+            TooltipState::Dismissed(DismissReason::Lifetime)
+        );
+
+        // The tooltip stays dismissed while the cursor stays on the target entity.
+        for _ in 0..10 {
+            update(&mut world, Vec2::new(101.0, 100.0), None);
+            assert_eq!(
+                state(&world),
+                TooltipState::Dismissed(DismissReason::Lifetime)
+            );
+        }
+
+        // Re-entering the target entity reactivates the tooltip.
+        leave(&mut world, Vec2::new(200.0, 100.0), target);
+        assert_eq!(state(&world), TooltipState::Inactive);
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        assert_eq!(state(&world), TooltipState::Active);
+    }
+
+    #[test]
+    fn hold_button_gates_activation() {
+        let mut world = setup();
+        world.init_resource::<ButtonInput<MouseButton>>();
+        let target = world
+            .spawn(
+                Tooltip::cursor("Hello, world!")
+                    .with_activation(TooltipActivation::hold(MouseButton::Right)),
+            )
+            .id();
+
+        // Hovering without the hold button doesn't activate the tooltip.
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        for _ in 0..10 {
+            update(&mut world, Vec2::new(100.0, 100.0), None);
+            assert_eq!(state(&world), TooltipState::Delayed);
+        }
+
+        // Pressing the hold button activates the tooltip.
+        world
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Right);
+        update(&mut world, Vec2::new(100.0, 100.0), None);
+        assert_eq!(state(&world), TooltipState::Active);
+
+        // Releasing the hold button deactivates the tooltip.
+        world
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .release(MouseButton::Right);
+        update(&mut world, Vec2::new(100.0, 100.0), None);
+        assert_eq!(state(&world), TooltipState::Delayed);
+    }
+
+    #[test]
+    fn delayed_content_switches_to_content_on_activation() {
+        let mut world = setup();
+        let target = world
+            .spawn(
+                Tooltip::cursor("Hello, world!")
+                    .with_delayed_content("...")
+                    .with_activation(100),
+            )
+            .id();
+        let primary_text = world.resource::<TooltipSettings>().text;
+        let displayed_text = |world: &mut World| {
+            world.run_system_once(show_tooltip).unwrap();
+            world.resource_mut::<Events<ShowTooltip>>().clear();
+            world.get::<RichText>(primary_text).unwrap().sections[0]
+                .value
+                .clone()
+        };
+
+        // The delayed content is displayed during the activation delay.
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        for _ in 0..6 {
+            update(&mut world, Vec2::new(100.0, 100.0), None);
+            assert_eq!(state(&world), TooltipState::Delayed);
+            assert_eq!(displayed_text(&mut world), "...");
+        }
+
+        // The content is displayed once the tooltip is active.
+        update(&mut world, Vec2::new(100.0, 100.0), None);
+        assert_eq!(state(&world), TooltipState::Active);
+        assert_eq!(displayed_text(&mut world), "Hello, world!");
+    }
+
+    #[test]
+    fn hide_delay_lingers_until_reentry() {
+        let mut world = setup();
+        let activation = TooltipActivation {
+            hide_delay: 100,
+            ..TooltipActivation::from(100)
+        };
+        let target = world
+            .spawn(Tooltip::cursor("Hello, world!").with_activation(activation))
+            .id();
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        for _ in 0..7 {
+            update(&mut world, Vec2::new(100.0, 100.0), None);
+        }
+        assert_eq!(state(&world), TooltipState::Active);
+
+        // Re-entering the target entity while lingering cancels the hide delay.
+        leave(&mut world, Vec2::new(200.0, 100.0), target);
+        update(&mut world, Vec2::new(200.0, 100.0), None);
+        assert_eq!(state(&world), TooltipState::Active);
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        for _ in 0..10 {
+            update(&mut world, Vec2::new(100.0, 100.0), None);
+            assert_eq!(state(&world), TooltipState::Active);
+            assert!(!world.resource::<TooltipContext>().lingering);
+        }
+
+        // The tooltip stays active for the hide delay after leaving the target entity.
+        leave(&mut world, Vec2::new(200.0, 100.0), target);
+        for _ in 0..6 {
+            update(&mut world, Vec2::new(200.0, 100.0), None);
+            assert_eq!(state(&world), TooltipState::Active);
+        }
+        update(&mut world, Vec2::new(200.0, 100.0), None);
+        assert_eq!(state(&world), TooltipState::Inactive);
+    }
+
+    #[test]
+    fn reentering_delayed_target_keeps_timer() {
+        let mut world = setup();
+        let target = world
+            .spawn(Tooltip::cursor("Hello, world!").with_activation(100))
+            .id();
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        for _ in 0..3 {
+            update(&mut world, Vec2::new(100.0, 100.0), None);
+        }
+        assert_eq!(world.resource::<TooltipContext>().timer, 100 - 3 * 16);
+
+        // Leave and re-enter the target entity within the same frame.
+        let location = location(&mut world, Vec2::new(100.0, 100.0));
+        let hit = HitData::new(Entity::PLACEHOLDER, 0.0, None, None);
+        world.send_event(Pointer::new(
+            PointerId::Mouse,
+            location,
+            target,
+            Out { hit },
+        ));
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        assert_eq!(state(&world), TooltipState::Delayed);
+        assert_eq!(world.resource::<TooltipContext>().timer, 100 - 4 * 16);
+    }
+
+    #[test]
+    fn recent_delay_applies_within_recent_window() {
+        let mut world = setup();
+        let activation = TooltipActivation {
+            recent_window: 500,
+            recent_delay: 50,
+            ..TooltipActivation::from(200)
+        };
+        let target = world
+            .spawn(Tooltip::cursor("Hello, world!").with_activation(activation))
+            .id();
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        assert_eq!(world.resource::<TooltipContext>().base_delay, 200);
+        for _ in 0..13 {
+            update(&mut world, Vec2::new(100.0, 100.0), None);
+        }
+        assert_eq!(state(&world), TooltipState::Active);
+
+        // Re-entering within the recent window uses the recent delay.
+        leave(&mut world, Vec2::new(200.0, 100.0), target);
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        assert_eq!(world.resource::<TooltipContext>().base_delay, 50);
+        for _ in 0..4 {
+            update(&mut world, Vec2::new(100.0, 100.0), None);
+        }
+        assert_eq!(state(&world), TooltipState::Active);
+
+        // Re-entering after the recent window uses the normal delay.
+        leave(&mut world, Vec2::new(200.0, 100.0), target);
+        for _ in 0..32 {
+            update(&mut world, Vec2::new(200.0, 100.0), None);
+        }
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        assert_eq!(world.resource::<TooltipContext>().base_delay, 200);
+    }
+
+    #[test]
+    fn strict_continuous_leave_cancels_delay() {
+        let mut world = setup();
+        let activation = TooltipActivation {
+            strict_continuous: true,
+            ..TooltipActivation::from(100)
+        };
+        let transfer = TooltipTransfer {
+            from_active: false,
+            ..TooltipTransfer::SHORT
+        };
+        let target = world
+            .spawn(
+                Tooltip::cursor("Hello, world!")
+                    .with_activation(activation)
+                    .with_transfer(transfer),
+            )
+            .id();
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        for _ in 0..3 {
+            update(&mut world, Vec2::new(100.0, 100.0), None);
+        }
+
+        // Leaving during the activation delay cancels it without starting a transfer.
+        leave(&mut world, Vec2::new(200.0, 100.0), target);
+        assert_eq!(state(&world), TooltipState::Inactive);
+        assert_eq!(world.resource::<TooltipContext>().timer, 0);
+
+        // Re-entering restarts the full activation delay.
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        assert_eq!(state(&world), TooltipState::Delayed);
+        assert_eq!(world.resource::<TooltipContext>().timer, 100);
+        for _ in 0..6 {
+            update(&mut world, Vec2::new(100.0, 100.0), None);
+            assert_eq!(state(&world), TooltipState::Delayed);
+        }
+        update(&mut world, Vec2::new(100.0, 100.0), None);
+        assert_eq!(state(&world), TooltipState::Active);
+    }
+
+    #[test]
+    fn no_transfer_in_waits_for_delay() {
+        for no_transfer_in in [false, true] {
+            let mut world = setup();
+            let tooltip = Tooltip::cursor("Hello, world!")
+                .with_activation(100)
+                .with_transfer(TooltipTransfer::SHORT);
+            let a = world.spawn(tooltip.clone()).id();
+            let b = world
+                .spawn(tooltip.with_transfer(TooltipTransfer {
+                    no_transfer_in,
+                    ..TooltipTransfer::SHORT
+                }))
+                .id();
+            update(&mut world, Vec2::new(100.0, 100.0), Some(a));
+            for _ in 0..7 {
+                update(&mut world, Vec2::new(100.0, 100.0), None);
+            }
+            assert_eq!(state(&world), TooltipState::Active);
+
+            // Move to another target entity in the same transfer group.
+            leave(&mut world, Vec2::new(200.0, 100.0), a);
+            update(&mut world, Vec2::new(200.0, 100.0), Some(b));
+            let expected = if no_transfer_in {
+                TooltipState::Delayed
+            } else {
+                TooltipState::Active
+            };
+            assert_eq!(state(&world), expected);
+        }
+    }
+
+    #[test]
+    fn layer_boost_transfers_to_higher_layer() {
+        for boosted in [false, true] {
+            let mut world = setup();
+            let tooltip = Tooltip::cursor("Hello, world!")
+                .with_activation(100)
+                .with_transfer(TooltipTransfer::SHORT);
+            let a = world.spawn(tooltip.clone()).id();
+            let b = world
+                .spawn(tooltip.with_transfer(TooltipTransfer {
+                    layer: 1,
+                    ..TooltipTransfer::SHORT
+                }))
+                .id();
+            if boosted {
+                world.entity_mut(b).insert(TooltipLayerBoost);
+            }
+            update(&mut world, Vec2::new(100.0, 100.0), Some(a));
+            for _ in 0..7 {
+                update(&mut world, Vec2::new(100.0, 100.0), None);
+            }
+            assert_eq!(state(&world), TooltipState::Active);
+
+            // Move to a target entity in a higher layer of the same transfer group.
+            leave(&mut world, Vec2::new(200.0, 100.0), a);
+            update(&mut world, Vec2::new(200.0, 100.0), Some(b));
+            let expected = if boosted {
+                TooltipState::Active
+            } else {
+                TooltipState::Delayed
+            };
+            assert_eq!(state(&world), expected);
+        }
+    }
+
+    #[test]
+    fn delay_scale_scales_activation_delays() {
+        assert_eq!(scale_delay(100, 2.0), 200);
+        assert_eq!(scale_delay(100, 0.5), 50);
+        assert_eq!(scale_delay(u16::MAX, 2.0), u16::MAX);
+
+        let mut world = setup();
+        world.resource_mut::<TooltipSettings>().delay_scale = 2.0;
+        let a = world
+            .spawn(Tooltip::cursor("Hello, world!").with_activation(100))
+            .id();
+        let b = world
+            .spawn(Tooltip::cursor("Hello, world!").with_activation(50))
+            .id();
+        update(&mut world, Vec2::new(100.0, 100.0), Some(a));
+        assert_eq!(world.resource::<TooltipContext>().timer, 200);
+        leave(&mut world, Vec2::new(200.0, 100.0), a);
+        update(&mut world, Vec2::new(200.0, 100.0), Some(b));
+        assert_eq!(world.resource::<TooltipContext>().timer, 100);
+    }
+
+    #[test]
+    fn zero_delay_scale_activates_immediately() {
+        assert_eq!(scale_delay(400, 0.0), 0);
+
+        let mut world = setup();
+        world.resource_mut::<TooltipSettings>().delay_scale = 0.0;
+        let target = world
+            .spawn(Tooltip::cursor("Hello, world!").with_activation(400))
+            .id();
+        update(&mut world, Vec2::new(100.0, 100.0), Some(target));
+        assert_eq!(state(&world), TooltipState::Active);
+    }
+
+    #[test]
+    fn cursor_in_unfocused_window() {
+        let mut world = setup();
+        let mut window = Window {
+            focused: false,
+            ..Default::default()
+        };
+        window.set_cursor_position(Some(Vec2::new(50.0, 60.0)));
+        let window = world.spawn(window).id();
+        world.spawn((
+            Camera {
+                target: RenderTarget::Window(WindowRef::Entity(window)),
+                ..Default::default()
+            },
+            GlobalTransform::default(),
+        ));
+
+        // Fall back to an unfocused window if the focused window doesn't report a cursor.
+        world.run_system_once(update_tooltip_context).unwrap();
+        assert_eq!(
+            world.resource::<TooltipContext>().last_cursor_pos,
+            Vec2::new(50.0, 60.0),
+        );
+
+        // Prefer the focused window if it does.
+        let mut primary_window = world
+            .query_filtered::<&mut Window, With<PrimaryWindow>>()
+            .single_mut(&mut world)
+            .unwrap();
+        primary_window.set_cursor_position(Some(Vec2::new(100.0, 100.0)));
+        world.run_system_once(update_tooltip_context).unwrap();
+        assert_eq!(
+            world.resource::<TooltipContext>().last_cursor_pos,
+            Vec2::new(100.0, 100.0),
+        );
+    }
+
+    #[test]
+    fn hidden_duration_uses_tooltip_time_source() {
+        let mut world = setup();
+        world.init_resource::<Events<TooltipHidden>>();
+        // Keep the system's shown tooltip between updates.
+        let mut schedule = Schedule::default();
+        schedule.add_systems(report_tooltip_hidden);
+        let target = world.spawn_empty().id();
+        let mut ctx = world.resource_mut::<TooltipContext>();
+        ctx.tooltip.time_source = TimeSource::Real;
+        ctx.target = target;
+        ctx.state = TooltipState::Active;
+        schedule.run(&mut world);
+
+        // Virtual time is paused.
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(500));
+        world.resource_mut::<TooltipContext>().state = TooltipState::Inactive;
+        schedule.run(&mut world);
+
+        let events = world.resource::<Events<TooltipHidden>>();
+        let hidden = events.iter_current_update_events().collect::<Vec<_>>();
+        assert_eq!(hidden.len(), 1);
+        assert_eq!(hidden[0].entity, target);
+        assert_eq!(hidden[0].visible, Duration::from_millis(500));
+    }
+}
@@ -0,0 +1,249 @@
+use core::time::Duration;
+
+use bevy_app::{App, PreUpdate};
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic as _};
+use bevy_ecs::{
+    entity::Entity,
+    schedule::IntoScheduleConfigs as _,
+    system::{Local, Res},
+};
+use bevy_time::{Real, Time};
+
+use crate::{
+    TimeSource, TooltipSystems,
+    context::{DismissReason, TooltipContext, TooltipState},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_diagnostic(Diagnostic::new(TooltipDiagnostics::ACTIVATIONS).with_suffix("/s"));
+    app.register_diagnostic(
+        Diagnostic::new(TooltipDiagnostics::ACTIVATION_DELAY).with_suffix("ms"),
+    );
+    app.register_diagnostic(
+        Diagnostic::new(TooltipDiagnostics::DISMISSALS_DISTANCE).with_suffix("/s"),
+    );
+    app.register_diagnostic(
+        Diagnostic::new(TooltipDiagnostics::DISMISSALS_SUPPRESSED).with_suffix("/s"),
+    );
+    app.register_diagnostic(
+        Diagnostic::new(TooltipDiagnostics::DISMISSALS_LIFETIME).with_suffix("/s"),
+    );
+    app.register_diagnostic(
+        Diagnostic::new(TooltipDiagnostics::DISMISSALS_CLICK).with_suffix("/s"),
+    );
+    app.add_systems(
+        PreUpdate,
+        update_tooltip_diagnostics.after(TooltipSystems::Content),
+    );
+}
+
+/// The [`DiagnosticPath`]s of the tooltip diagnostics.
+///
+/// These are registered with the `DiagnosticsStore` by [`TooltipPlugin`](crate::TooltipPlugin)
+/// when the `diagnostics` feature is enabled.
+pub struct TooltipDiagnostics;
+
+impl TooltipDiagnostics {
+    /// The number of tooltip activations per second.
+    pub const ACTIVATIONS: DiagnosticPath = DiagnosticPath::const_new("tooltip/activations");
+    /// The duration from hovering a target to activating its tooltip (in milliseconds).
+    ///
+    /// This is measured on the tooltip's [time source](crate::Tooltip::time_source).
+    pub const ACTIVATION_DELAY: DiagnosticPath =
+        DiagnosticPath::const_new("tooltip/activation_delay");
+    /// The number of tooltips dismissed per second by the cursor leaving the dismissal radius.
+    pub const DISMISSALS_DISTANCE: DiagnosticPath =
+        DiagnosticPath::const_new("tooltip/dismissals/distance");
+    /// The number of tooltips dismissed per second by their group being suppressed.
+    pub const DISMISSALS_SUPPRESSED: DiagnosticPath =
+        DiagnosticPath::const_new("tooltip/dismissals/suppressed");
+    /// The number of tooltips dismissed per second by exceeding the max lifetime.
+    pub const DISMISSALS_LIFETIME: DiagnosticPath =
+        DiagnosticPath::const_new("tooltip/dismissals/lifetime");
+    /// The number of tooltips dismissed per second by a click.
+    pub const DISMISSALS_CLICK: DiagnosticPath =
+        DiagnosticPath::const_new("tooltip/dismissals/click");
+}
+
+/// The tooltip state as of the previous diagnostics update.
+struct DiagnosticsTracker {
+    state: TooltipState,
+    target: Entity,
+    hover_start: Duration,
+}
+
+impl Default for DiagnosticsTracker {
+    fn default() -> Self {
+        Self {
+            state: TooltipState::Inactive,
+            target: Entity::PLACEHOLDER,
+            hover_start: Duration::ZERO,
+        }
+    }
+}
+
+fn update_tooltip_diagnostics(
+    mut diagnostics: Diagnostics,
+    mut tracker: Local<DiagnosticsTracker>,
+    ctx: Res<TooltipContext>,
+    time: Res<Time>,
+    real_time: Res<Time<Real>>,
+) {
+    // Measure rates per real second, so they're unaffected by a paused or scaled virtual clock.
+    let dt = real_time.delta_secs_f64();
+    if dt <= 0.0 {
+        return;
+    }
+    // Measure the activation delay on the same clock as the tooltip's activation timer.
+    let (elapsed, delta) = match ctx.tooltip.time_source {
+        TimeSource::Virtual => (time.elapsed(), time.delta()),
+        TimeSource::Real => (real_time.elapsed(), real_time.delta()),
+    };
+    let target_changed = tracker.target != ctx.target;
+
+    // Start timing the activation delay when a new target is hovered.
+    if matches!(ctx.state, TooltipState::Delayed | TooltipState::Active)
+        && (target_changed || matches!(tracker.state, TooltipState::Inactive))
+    {
+        tracker.hover_start = elapsed.saturating_sub(delta);
+    }
+
+    // Measure activations.
+    let activated = matches!(ctx.state, TooltipState::Active)
+        && (target_changed || !matches!(tracker.state, TooltipState::Active));
+    diagnostics.add_measurement(&TooltipDiagnostics::ACTIVATIONS, || {
+        if activated { 1.0 / dt } else { 0.0 }
+    });
+    if activated {
+        let delay = elapsed.saturating_sub(tracker.hover_start);
+        diagnostics.add_measurement(&TooltipDiagnostics::ACTIVATION_DELAY, || {
+            delay.as_secs_f64() * 1000.0
+        });
+    }
+
+    // Measure dismissals by reason.
+    let reason = match ctx.state {
+        TooltipState::Dismissed(reason) if tracker.state != ctx.state || target_changed => {
+            Some(reason)
+        }
+        _ => None,
+    };
+    for (path, expected) in [
+        (
+            &TooltipDiagnostics::DISMISSALS_DISTANCE,
+            DismissReason::Distance,
+        ),
+        (
+            &TooltipDiagnostics::DISMISSALS_SUPPRESSED,
+            DismissReason::Suppressed,
+        ),
+        (
+            &TooltipDiagnostics::DISMISSALS_LIFETIME,
+            DismissReason::Lifetime,
+        ),
+        (&TooltipDiagnostics::DISMISSALS_CLICK, DismissReason::Click),
+    ] {
+        diagnostics.add_measurement(path, || {
+            if reason == Some(expected) {
+                1.0 / dt
+            } else {
+                0.0
+            }
+        });
+    }
+
+    tracker.state = ctx.state;
+    tracker.target = ctx.target;
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use bevy_diagnostic::DiagnosticsStore;
+    use bevy_ecs::{schedule::Schedule, world::World};
+
+    use super::*;
+
+    fn setup() -> World {
+        let mut world = World::new();
+        let mut store = DiagnosticsStore::default();
+        store.add(Diagnostic::new(TooltipDiagnostics::ACTIVATIONS));
+        store.add(Diagnostic::new(TooltipDiagnostics::ACTIVATION_DELAY));
+        world.insert_resource(store);
+        world.init_resource::<TooltipContext>();
+        world.init_resource::<Time>();
+        world.init_resource::<Time<Real>>();
+        world
+    }
+
+    fn update(
+        world: &mut World,
+        schedule: &mut Schedule,
+        virtual_ms: u64,
+        real_ms: u64,
+        state: TooltipState,
+    ) {
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(virtual_ms));
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(real_ms));
+        world.resource_mut::<TooltipContext>().state = state;
+        schedule.run(world);
+    }
+
+    fn measurements(world: &World, path: &DiagnosticPath) -> Vec<f64> {
+        world
+            .resource::<DiagnosticsStore>()
+            .get(path)
+            .unwrap()
+            .measurements()
+            .map(|measurement| measurement.value)
+            .collect()
+    }
+
+    #[test]
+    fn activations_are_counted() {
+        let mut world = setup();
+        // Keep the system's tracker between updates, and apply its measurements after each.
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_tooltip_diagnostics);
+        world.resource_mut::<TooltipContext>().target = world.spawn_empty().id();
+        for _ in 0..2 {
+            update(&mut world, &mut schedule, 100, 100, TooltipState::Delayed);
+            update(&mut world, &mut schedule, 100, 100, TooltipState::Active);
+            update(&mut world, &mut schedule, 100, 100, TooltipState::Inactive);
+        }
+
+        let activations = measurements(&world, &TooltipDiagnostics::ACTIVATIONS);
+        assert_eq!(activations.len(), 6);
+        assert_eq!(activations.iter().filter(|&&rate| rate > 0.0).count(), 2);
+        assert_eq!(
+            measurements(&world, &TooltipDiagnostics::ACTIVATION_DELAY),
+            [200.0, 200.0],
+        );
+    }
+
+    #[test]
+    fn activation_delay_uses_tooltip_time_source() {
+        let mut world = setup();
+        // Keep the system's tracker between updates, and apply its measurements after each.
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_tooltip_diagnostics);
+        let target = world.spawn_empty().id();
+        let mut ctx = world.resource_mut::<TooltipContext>();
+        ctx.tooltip.time_source = TimeSource::Real;
+        ctx.target = target;
+
+        // Virtual time is paused.
+        update(&mut world, &mut schedule, 0, 100, TooltipState::Delayed);
+        update(&mut world, &mut schedule, 0, 300, TooltipState::Active);
+
+        assert_eq!(
+            measurements(&world, &TooltipDiagnostics::ACTIVATION_DELAY),
+            [400.0],
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use bevy_app::{App, PreUpdate};
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::ReflectResource;
+use bevy_ecs::{
+    change_detection::DetectChanges as _,
+    entity::Entity,
+    event::EventWriter,
+    query::With,
+    resource::Resource,
+    schedule::IntoScheduleConfigs as _,
+    system::{Local, Query, Res, ResMut},
+};
+use tiny_bail::prelude::*;
+
+use crate::{
+    Tooltip, TooltipSettings, TooltipSystems,
+    context::{HideTooltip, PresetQuery, ShowTooltip, TooltipContext, TooltipState},
+    world::world_tooltip_inactive,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    #[cfg(feature = "bevy_reflect")]
+    app.register_type::<ForcedTooltip>();
+    app.init_resource::<ForcedTooltip>();
+    app.add_systems(
+        PreUpdate,
+        update_forced_tooltip
+            .run_if(world_tooltip_inactive)
+            .in_set(TooltipSystems::Detection),
+    );
+}
+
+/// A [`Resource`] that forces an entity's [`Tooltip`] to be active regardless of the cursor
+/// position, such as for a tutorial pointing at an arbitrary element.
+///
+/// The tooltip stays active until it's released, and is not dismissed in the meantime. A
+/// [target-anchored](crate::TooltipPlacement::target_anchor) placement is recommended, because
+/// the cursor may be anywhere.
+///
+/// While this is set, hovered entities will not activate their tooltips. An entity without a
+/// [`Tooltip`] is ignored with a warning.
+#[derive(Resource, Clone, Default, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Resource)
+)]
+pub struct ForcedTooltip(pub Option<Entity>);
+
+impl ForcedTooltip {
+    /// Force the tooltip of the given entity to be active.
+    pub fn force(&mut self, entity: Entity) {
+        self.0 = Some(entity);
+    }
+
+    /// Release the forced tooltip, returning to hover detection.
+    pub fn release(&mut self) {
+        self.0 = None;
+    }
+}
+
+pub(crate) fn forced_tooltip_inactive(
+    forced_tooltip: Res<ForcedTooltip>,
+    tooltip_query: Query<(), With<Tooltip>>,
+) -> bool {
+    forced_tooltip
+        .0
+        .is_none_or(|entity| !tooltip_query.contains(entity))
+}
+
+fn update_forced_tooltip(
+    forced_tooltip: Res<ForcedTooltip>,
+    mut ctx: ResMut<TooltipContext>,
+    primary: Res<TooltipSettings>,
+    mut hide_tooltip: EventWriter<HideTooltip>,
+    mut show_tooltip: EventWriter<ShowTooltip>,
+    tooltip_query: Query<&Tooltip>,
+    presets: PresetQuery,
+    mut forced: Local<bool>,
+) {
+    let old_entity = ctx
+        .displayed_content()
+        .map(|content| content.entity(&primary));
+    let old_target = ctx.target;
+    let old_state = ctx.state;
+
+    // Ignore an entity without a tooltip so that hover detection keeps running.
+    let forced_entity = forced_tooltip.0.filter(|&entity| {
+        if forced_tooltip.is_changed() {
+            r!(tooltip_query.get(entity));
+        }
+        tooltip_query.contains(entity)
+    });
+    if let Some(entity) = forced_entity
+        && let Ok(tooltip) = tooltip_query.get(entity)
+    {
+        *forced = true;
+        ctx.world = false;
+        ctx.target = entity;
+        ctx.tooltip = presets.resolve(tooltip).into_owned();
+        ctx.state = TooltipState::Active;
+        ctx.lingering = false;
+        if old_target == ctx.target && old_state == TooltipState::Active {
+            return;
+        }
+    } else if *forced {
+        *forced = false;
+        ctx.state = TooltipState::Inactive;
+        ctx.timer = 0;
+    } else {
+        return;
+    }
+
+    if let Some(entity) = old_entity {
+        hide_tooltip.write(HideTooltip { entity });
+    }
+    if ctx.displayed_content().is_some() {
+        show_tooltip.write(ShowTooltip);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{event::Events, system::RunSystemOnce as _, world::World};
+
+    use super::*;
+
+    fn setup() -> World {
+        let mut world = World::new();
+        let settings =
+            TooltipSettings::new(&mut world, Entity::PLACEHOLDER, Entity::PLACEHOLDER, true);
+        world.insert_resource(settings);
+        world.init_resource::<ForcedTooltip>();
+        world.init_resource::<TooltipContext>();
+        world.init_resource::<Events<HideTooltip>>();
+        world.init_resource::<Events<ShowTooltip>>();
+        world
+    }
+
+    #[test]
+    fn forcing_tooltip_activates_it() {
+        let mut world = setup();
+        let target = world.spawn(Tooltip::cursor("Hello, world!")).id();
+        world.resource_mut::<ForcedTooltip>().force(target);
+        world.run_system_once(update_forced_tooltip).unwrap();
+
+        let ctx = world.resource::<TooltipContext>();
+        assert_eq!(ctx.state, TooltipState::Active);
+        assert_eq!(ctx.target, target);
+        assert_eq!(world.resource::<Events<ShowTooltip>>().len(), 1);
+    }
+
+    #[test]
+    fn forcing_entity_without_tooltip_is_ignored() {
+        let mut world = setup();
+        let target = world.spawn_empty().id();
+        world.resource_mut::<ForcedTooltip>().force(target);
+        world.run_system_once(update_forced_tooltip).unwrap();
+
+        assert_eq!(
+            world.resource::<TooltipContext>().state,
+            TooltipState::Inactive
+        );
+        assert!(world.resource::<Events<ShowTooltip>>().is_empty());
+    }
+}
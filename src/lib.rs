@@ -40,9 +40,24 @@
 
 extern crate alloc;
 
+#[cfg(feature = "audio")]
+mod audio;
+#[cfg(feature = "auto_tooltip")]
+mod auto;
+mod backdrop;
 mod context;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+mod forced;
 mod placement;
+#[cfg(feature = "presets")]
+mod preset;
 mod rich_text;
+mod task;
+#[cfg(feature = "tuning")]
+mod tuning;
+mod virtual_cursor;
+mod world;
 
 /// Re-exports for commonly used types.
 ///
@@ -53,18 +68,30 @@ mod rich_text;
 /// ```
 pub mod prelude {
     pub use super::{
-        Tooltip, TooltipActivation, TooltipContent, TooltipPlacement, TooltipPlugin,
-        TooltipSettings, TooltipSystems, TooltipTransfer,
-        rich_text::{RichText, TextSection, TextStyle},
+        AutoFit, DelayMode, DismissDistance, FallbackTooltip, ForcedTooltip, ReactivatePolicy,
+        SuppressedTooltipGroups, TimeSource, Tooltip, TooltipActivation, TooltipAvoidRects,
+        TooltipBackdrop, TooltipContent, TooltipContentTask, TooltipLayerBoost, TooltipListItem,
+        TooltipMaxWidth, TooltipPassthrough, TooltipPivot, TooltipPlacement, TooltipPlugin,
+        TooltipSettings, TooltipSuppressSubtree, TooltipSystems, TooltipTextSlot, TooltipTransfer,
+        VirtualCursor, WorldTooltip,
+        rich_text::{InputGlyph, InputGlyphResolver, RichText, TextSection, TextStyle},
     };
 }
 
+use core::time::Duration;
+
 use alloc::{
+    collections::BTreeSet,
+    format,
     string::{String, ToString as _},
     vec::Vec,
 };
 
 use bevy_app::{Plugin, PostUpdate, PreUpdate};
+#[cfg(feature = "audio")]
+use bevy_asset::Handle;
+#[cfg(feature = "audio")]
+use bevy_audio::AudioSource;
 use bevy_color::Color;
 #[cfg(feature = "bevy_reflect")]
 use bevy_ecs::reflect::{ReflectComponent, ReflectResource};
@@ -80,6 +107,8 @@ use bevy_ecs::{
     system::{Commands, Query, Res},
     world::World,
 };
+use bevy_input::mouse::MouseButton;
+use bevy_math::{Dir2, Vec2};
 use bevy_picking::Pickable;
 use bevy_render::view::Visibility;
 use bevy_sprite::Anchor;
@@ -87,21 +116,44 @@ use bevy_text::JustifyText;
 use bevy_transform::TransformSystem;
 use bevy_ui::{BackgroundColor, GlobalZIndex, Node, PositionType, UiRect, UiSystem, Val};
 
-pub use placement::TooltipPlacement;
-pub use rich_text::{RichText, RichTextSystems, TextSection, TextStyle};
+#[cfg(feature = "auto_tooltip")]
+pub use auto::{AutoTooltip, TooltipText, generate_auto_tooltips};
+pub use backdrop::TooltipBackdrop;
+pub use context::{
+    RefreshTooltip, TooltipHidden, TooltipRelation, TooltipRelations, TooltipWarm, TooltipWarmable,
+    UpdateTooltipSpan, refresh_tooltips_on_change,
+};
+#[cfg(feature = "diagnostics")]
+pub use diagnostics::TooltipDiagnostics;
+pub use forced::ForcedTooltip;
+pub use placement::{TooltipAvoidRects, TooltipPivot, TooltipPlacement, compute_tooltip_position};
+#[cfg(feature = "presets")]
+pub use preset::{TooltipPreset, TooltipPresetSet, TooltipPresets};
+pub use rich_text::{
+    InputGlyph, InputGlyphResolver, RichText, RichTextSystems, TextSection, TextStyle,
+};
+pub use task::TooltipContentTask;
+#[cfg(feature = "tuning")]
+pub use tuning::{TooltipTuning, TooltipTuningCurve};
+pub use virtual_cursor::{VIRTUAL_POINTER_ID, VirtualCursor};
+pub use world::{WorldTooltip, WorldTooltipTarget};
 
 /// A [`Plugin`] that sets up the tooltip widget system.
 ///
+/// By default, this spawns a primary tooltip container and text entity on build, so no manual
+/// setup is required. Provide custom entities to opt out and take full control of the primary
+/// tooltip's appearance.
+///
 /// Use the [`TooltipSettings`] resource to make changes while the app is already running.
 pub struct TooltipPlugin {
     /// Set a custom entity for [`TooltipSettings::container`], or spawn the default container
-    /// entity if `None`.
+    /// entity if [`Entity::PLACEHOLDER`].
     ///
     /// This entity should include all of the required components of [`Node`], with
     /// [`Visibility::Hidden`] and [`Node::position_type`] set to [`PositionType::Absolute`].
     pub container: Entity,
     /// Set a custom entity for [`TooltipSettings::text`], or spawn the default text entity if
-    /// `None`.
+    /// [`Entity::PLACEHOLDER`].
     ///
     /// This entity should include all of the required components of [`Node`], along with a
     /// [`RichText`] component, and be a child of [`Self::container`].
@@ -118,13 +170,26 @@ impl Plugin for TooltipPlugin {
             TooltipSettings::new(app.world_mut(), self.container, self.text, self.enabled);
         app.insert_resource(settings);
 
+        #[cfg(feature = "bevy_reflect")]
+        app.register_type::<SuppressedTooltipGroups>();
+        app.init_resource::<SuppressedTooltipGroups>();
+
         #[cfg(feature = "bevy_reflect")]
         app.register_type::<Tooltip>();
+        #[cfg(feature = "bevy_reflect")]
+        app.register_type::<TooltipTextSlot>();
+        #[cfg(feature = "bevy_reflect")]
+        app.register_type::<TooltipPassthrough>();
+        #[cfg(feature = "bevy_reflect")]
+        app.register_type::<TooltipLayerBoost>();
+        #[cfg(feature = "bevy_reflect")]
+        app.register_type::<TooltipSuppressSubtree>();
 
         app.configure_sets(
             PreUpdate,
             (
                 UiSystem::Focus,
+                TooltipSystems::Detection.run_if(tooltips_enabled),
                 TooltipSystems::Content.run_if(tooltips_enabled),
             )
                 .chain(),
@@ -141,9 +206,28 @@ impl Plugin for TooltipPlugin {
             PreUpdate,
             sync_tooltip_settings
                 .run_if(resource_changed::<TooltipSettings>)
-                .before(TooltipSystems::Content),
+                .before(TooltipSystems::Detection),
         );
-        app.add_plugins((context::plugin, placement::plugin, rich_text::plugin));
+        app.add_plugins((
+            backdrop::plugin,
+            context::plugin,
+            forced::plugin,
+            placement::plugin,
+            rich_text::plugin,
+            task::plugin,
+            virtual_cursor::plugin,
+            world::plugin,
+        ));
+        #[cfg(feature = "diagnostics")]
+        app.add_plugins(diagnostics::plugin);
+        #[cfg(feature = "presets")]
+        app.add_plugins(preset::plugin);
+        #[cfg(feature = "auto_tooltip")]
+        app.add_plugins(auto::plugin);
+        #[cfg(feature = "audio")]
+        app.add_plugins(audio::plugin);
+        #[cfg(feature = "tuning")]
+        app.add_plugins(tuning::plugin);
     }
 }
 
@@ -160,7 +244,9 @@ impl Default for TooltipPlugin {
 /// A [`Resource`] containing the [`Entity`] IDs of the global primary tooltip.
 ///
 /// See [`TooltipPlugin`] to set up a custom primary tooltip.
-#[derive(Resource, Copy, Clone, Debug)]
+///
+/// See [`Self::snapshot`] to revert changes, such as live previews in a settings menu.
+#[derive(Resource, Copy, Clone, PartialEq, Debug)]
 #[cfg_attr(
     feature = "bevy_reflect",
     derive(bevy_reflect::Reflect),
@@ -168,11 +254,64 @@ impl Default for TooltipPlugin {
 )]
 pub struct TooltipSettings {
     /// The [`Entity`] ID of the UI node to be used as the primary tooltip.
+    ///
+    /// Its components can be modified to restyle the primary tooltip, such as by replacing its
+    /// [`BackgroundColor`] with a nine-slice [`ImageNode`](bevy_ui::widget::ImageNode).
     pub container: Entity,
     /// The [`Entity`] ID of the UI node to be used as the primary tooltip's text.
     pub text: Entity,
     /// Whether or not tooltips will be displayed.
     pub enabled: bool,
+    /// The cursor speed above which tooltips will not activate (in logical pixels per second).
+    ///
+    /// This prevents tooltips from flashing while the cursor sweeps quickly across many targets.
+    pub max_activation_speed: f32,
+    /// A debugging tooltip to display for hovered entities without a [`Tooltip`], or `None` to
+    /// disable.
+    pub fallback: Option<FallbackTooltip>,
+    /// The maximum duration a tooltip can stay active before it's dismissed, or `None` for no
+    /// limit.
+    pub max_lifetime: Option<Duration>,
+    /// Whether to round the tooltip position to whole physical pixels to keep its text sharp.
+    pub snap_to_pixel: bool,
+    /// A scale factor for the primary tooltip's font size and padding, and all tooltip offsets.
+    ///
+    /// Unlike [`UiScale`](bevy_ui::UiScale), this only affects tooltips.
+    pub scale: f32,
+    /// The distance from a [`TooltipWarmable`] UI node within which a [`TooltipWarm`] event will
+    /// be sent (in logical pixels), or `None` to disable.
+    pub warm_distance: Option<f32>,
+    /// A UI node to parent [`Self::container`] to, or `None` to leave it as is.
+    ///
+    /// This can be used to place the primary tooltip within an existing UI hierarchy (e.g. for
+    /// z-ordering with a HUD). The tooltip is still positioned in viewport coordinates, which are
+    /// converted to the parent's coordinate space, so the parent should not clip its children.
+    pub parent: Option<Entity>,
+    /// The rate at which tooltips placed at the cursor follow it while they're displayed (per
+    /// second), or `0.0` to keep them at the cursor position where they were activated.
+    ///
+    /// Higher values follow more tightly. The tooltip approaches the cursor exponentially, so it
+    /// lags behind while the cursor is moving. The [dismissal distance](TooltipDismissal::on_distance)
+    /// is still measured from the activation point.
+    pub follow_smoothing: f32,
+    /// The duration a tooltip placed at the cursor stays at its activation point before it
+    /// starts following the cursor.
+    ///
+    /// This lets the tooltip settle after it appears instead of immediately chasing the cursor.
+    /// It does nothing if [`Self::follow_smoothing`] is `0.0`.
+    pub follow_start_delay: Duration,
+    /// The duration of the slide from the old target to the new target when a tooltip transfers
+    /// between targets (in milliseconds), or `0` to move instantly.
+    ///
+    /// The slide only applies if the same tooltip entity is displayed for both targets.
+    pub transfer_slide: u16,
+    /// A backdrop to dim the rest of the UI while a tooltip is active, or `None` to disable.
+    pub backdrop: Option<TooltipBackdrop>,
+    /// A scale factor for all tooltips' activation delays.
+    ///
+    /// This can be exposed as an accessibility setting, so tooltips appear faster (below `1.0`)
+    /// or slower (above `1.0`). A scale of `0.0` activates tooltips immediately.
+    pub delay_scale: f32,
 }
 
 impl TooltipSettings {
@@ -216,11 +355,182 @@ impl TooltipSettings {
             container,
             text,
             enabled,
+            max_activation_speed: f32::INFINITY,
+            fallback: None,
+            max_lifetime: None,
+            snap_to_pixel: true,
+            scale: 1.0,
+            warm_distance: None,
+            parent: None,
+            follow_smoothing: 0.0,
+            follow_start_delay: Duration::ZERO,
+            transfer_slide: 0,
+            backdrop: None,
+            delay_scale: 1.0,
         }
     }
+
+    /// Take a snapshot of the settings to [restore](Self::restore) later.
+    ///
+    /// This can be used to revert live previews when a settings menu is cancelled. With the
+    /// `bevy_reflect` feature, the snapshot can be serialized through reflection.
+    pub fn snapshot(&self) -> Self {
+        *self
+    }
+
+    /// Restore the settings from a [snapshot](Self::snapshot).
+    pub fn restore(&mut self, snapshot: Self) {
+        *self = snapshot;
+    }
+}
+
+/// A named text slot in the primary tooltip container, to be filled by
+/// [`TooltipContent::Slots`].
+///
+/// To use slots, spawn [`RichText`] entities with this component as children of
+/// [`TooltipSettings::container`]. Slots that aren't named by the displayed content are cleared.
+#[derive(Component, Clone, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Component)
+)]
+#[require(RichText)]
+pub struct TooltipTextSlot(pub String);
+
+/// A marker [`Component`] for UI nodes or sprites that shouldn't block tooltip detection for the
+/// entities beneath them, such as a translucent overlay.
+///
+/// This requires a [`Pickable`] that doesn't block lower entities, so the entity itself remains
+/// hoverable. Passthrough entities are also skipped by the [fallback tooltip](FallbackTooltip).
+#[derive(Component, Copy, Clone, Default, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Component)
+)]
+#[require(Pickable = Pickable {
+    should_block_lower: false,
+    is_hoverable: true,
+})]
+pub struct TooltipPassthrough;
+
+/// A marker [`Component`] that lets a target entity's tooltip win transfer races, ignoring the
+/// [transfer layer](TooltipTransfer::layer) of the old tooltip.
+///
+/// This can be inserted and removed at runtime to temporarily prioritize a tooltip, such as
+/// during a guided tutorial.
+#[derive(Component, Copy, Clone, Default, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Component)
+)]
+pub struct TooltipLayerBoost;
+
+/// A marker [`Component`] that suppresses the tooltips of an entity and all of its descendants.
+///
+/// While suppressed, target entities are treated as if they had no tooltip. This can be used for
+/// a temporarily inert panel, such as one behind a modal dialog.
+#[derive(Component, Copy, Clone, Default, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Component)
+)]
+pub struct TooltipSuppressSubtree;
+
+/// A generated tooltip for hovered entities without a [`Tooltip`] component.
+///
+/// This is intended as a development and debugging aid, and is disabled by default. See
+/// [`TooltipSettings::fallback`] to enable it.
+///
+/// The tooltip displays the hovered entity's [`Name`] if it has one, or its [`Entity`] ID
+/// otherwise.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct FallbackTooltip {
+    /// How the tooltip will be positioned.
+    pub placement: TooltipPlacement,
+    /// The conditions for activating the tooltip.
+    pub activation: TooltipActivation,
+}
+
+impl FallbackTooltip {
+    fn tooltip(self, entity: Entity, name: Option<&Name>) -> Tooltip {
+        let text = match name {
+            Some(name) => name.to_string(),
+            None => format!("{entity}"),
+        };
+        Tooltip::cursor(text)
+            .with_placement(self.placement)
+            .with_activation(self.activation)
+    }
 }
 
-fn sync_tooltip_settings(mut commands: Commands, settings: Res<TooltipSettings>) {
+impl Default for FallbackTooltip {
+    fn default() -> Self {
+        Self {
+            placement: TooltipPlacement::CURSOR,
+            activation: TooltipActivation::IDLE,
+        }
+    }
+}
+
+/// A [`Resource`] containing the [transfer groups](TooltipTransfer::group) of tooltips that
+/// should not be displayed.
+///
+/// Tooltips in a suppressed group will not activate, and an active tooltip in a newly suppressed
+/// group will be dismissed.
+#[derive(Resource, Clone, Default, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Resource)
+)]
+pub struct SuppressedTooltipGroups(pub BTreeSet<i8>);
+
+impl SuppressedTooltipGroups {
+    /// Check whether a tooltip belongs to a suppressed group.
+    pub fn contains(&self, tooltip: &Tooltip) -> bool {
+        tooltip
+            .transfer
+            .group
+            .is_some_and(|group| self.0.contains(&group))
+    }
+}
+
+/// The padding of the primary tooltip container before applying [`TooltipSettings::scale`].
+#[derive(Component)]
+struct UnscaledPadding(UiRect);
+
+fn sync_tooltip_settings(
+    mut commands: Commands,
+    settings: Res<TooltipSettings>,
+    mut container_query: Query<(&mut Node, Option<&UnscaledPadding>)>,
+) {
+    if let Some(parent) = settings.parent {
+        commands.entity(settings.container).insert(ChildOf(parent));
+    }
+
+    if let Ok((mut node, unscaled)) = container_query.get_mut(settings.container) {
+        let padding = match unscaled {
+            Some(unscaled) => unscaled.0,
+            None => {
+                commands
+                    .entity(settings.container)
+                    .insert(UnscaledPadding(node.padding));
+                node.padding
+            }
+        };
+        node.padding = UiRect {
+            left: padding.left * settings.scale,
+            right: padding.right * settings.scale,
+            top: padding.top * settings.scale,
+            bottom: padding.bottom * settings.scale,
+        };
+    }
+
     if settings.enabled {
         commands
             .entity(settings.container)
@@ -241,6 +551,11 @@ fn tooltips_enabled(
 
 // TODO: Animation, wedge (like a speech bubble), easier content customization / icons.
 /// A [`Component`] that specifies a tooltip to be displayed on hover.
+///
+/// Hovering is detected via `bevy_picking`, so the entity must be pickable (e.g. a UI node, or a
+/// sprite with [`Pickable`]). UI nodes inside an [`Overflow::clip`](bevy_ui::Overflow::clip)
+/// container can only be hovered within the visible part of the clip region, so a target that has
+/// been scrolled out of view will not activate its tooltip.
 #[derive(Component, Clone, Debug)]
 #[cfg_attr(
     feature = "bevy_reflect",
@@ -250,6 +565,9 @@ fn tooltips_enabled(
 pub struct Tooltip {
     /// The tooltip content to display.
     pub content: TooltipContent,
+    /// The tooltip content to display while waiting for the activation delay, or `None` to
+    /// display nothing.
+    pub delayed_content: Option<TooltipContent>,
     /// How the tooltip will be positioned.
     pub placement: TooltipPlacement,
     /// The conditions for activating the tooltip.
@@ -258,6 +576,59 @@ pub struct Tooltip {
     pub dismissal: TooltipDismissal,
     /// The conditions for skipping the next tooltip's activation delay.
     pub transfer: TooltipTransfer,
+    /// Whether to color the tooltip text with the target entity's
+    /// [`TextColor`](bevy_text::TextColor).
+    ///
+    /// NOTE: This does nothing for custom tooltips, or if the target has no `TextColor`.
+    pub inherit_text_color: bool,
+    /// The max width of the primary tooltip container, or `None` to keep its own max width.
+    ///
+    /// NOTE: This does nothing for custom tooltips.
+    pub max_width: Option<TooltipMaxWidth>,
+    /// Shrink the font size of the primary tooltip text to fit its container, or `None` to keep
+    /// the font size as is.
+    ///
+    /// NOTE: This does nothing for custom tooltips.
+    pub autofit: Option<AutoFit>,
+    /// The maximum number of lines of primary tooltip text to display, or `None` for no limit.
+    ///
+    /// Excess lines are replaced by a "… (N more)" line. Only explicit line breaks are counted,
+    /// not line wrapping.
+    ///
+    /// NOTE: This does nothing for custom tooltips.
+    pub max_lines: Option<usize>,
+    /// The [`GlobalZIndex`] of the tooltip entity while it's displayed, or `None` to keep its own.
+    ///
+    /// This can be used to draw some tooltips above other UI, such as a modal dialog. The primary
+    /// tooltip container's own z-index is restored for tooltips without a z-index.
+    pub z_index: Option<i32>,
+    /// A condition for the tooltip to be eligible for activation, or `None` to always be eligible.
+    ///
+    /// This is evaluated when the target entity is hovered, and then once per frame while it's
+    /// the current target. While it's false, the target entity is treated as if it had no tooltip.
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    pub enabled_when: Option<fn(&World) -> bool>,
+    /// A condition to suppress the [distance dismissal](TooltipDismissal::on_distance), or `None`
+    /// to never suppress it.
+    ///
+    /// This is evaluated once per frame while the tooltip is active.
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    pub suppress_distance_dismiss_when: Option<fn(&World) -> bool>,
+    /// The clock used by the tooltip's timers.
+    pub time_source: TimeSource,
+    /// The name of a preset in `TooltipPresets` to apply on activation, or `None` for no preset.
+    ///
+    /// The preset overrides the other fields of the tooltip when it's activated or shown, but the
+    /// `Tooltip` itself is left unchanged.
+    ///
+    /// NOTE: This does nothing without the `presets` feature.
+    pub preset: Option<String>,
+    /// A sound to play when the tooltip is shown, or `None` to play nothing.
+    #[cfg(feature = "audio")]
+    pub show_sound: Option<Handle<AudioSource>>,
+    /// A sound to play when the tooltip is hidden, or `None` to play nothing.
+    #[cfg(feature = "audio")]
+    pub hide_sound: Option<Handle<AudioSource>>,
 }
 
 impl Tooltip {
@@ -265,10 +636,24 @@ impl Tooltip {
     pub fn fixed(placement: Anchor, content: impl Into<TooltipContent>) -> Self {
         Self {
             content: content.into(),
+            delayed_content: None,
             placement: placement.into(),
             activation: TooltipActivation::IMMEDIATE,
             dismissal: TooltipDismissal::NONE,
             transfer: TooltipTransfer::SHORT,
+            inherit_text_color: false,
+            max_width: None,
+            autofit: None,
+            max_lines: None,
+            z_index: None,
+            enabled_when: None,
+            suppress_distance_dismiss_when: None,
+            time_source: TimeSource::Virtual,
+            preset: None,
+            #[cfg(feature = "audio")]
+            show_sound: None,
+            #[cfg(feature = "audio")]
+            hide_sound: None,
         }
     }
 
@@ -276,10 +661,24 @@ impl Tooltip {
     pub fn cursor(content: impl Into<TooltipContent>) -> Self {
         Self {
             content: content.into(),
+            delayed_content: None,
             placement: TooltipPlacement::CURSOR,
             activation: TooltipActivation::IDLE,
             dismissal: TooltipDismissal::ON_CLICK,
             transfer: TooltipTransfer::NONE,
+            inherit_text_color: false,
+            max_width: None,
+            autofit: None,
+            max_lines: None,
+            z_index: None,
+            enabled_when: None,
+            suppress_distance_dismiss_when: None,
+            time_source: TimeSource::Virtual,
+            preset: None,
+            #[cfg(feature = "audio")]
+            show_sound: None,
+            #[cfg(feature = "audio")]
+            hide_sound: None,
         }
     }
 
@@ -288,12 +687,36 @@ impl Tooltip {
     /// NOTE: This does nothing for custom tooltips.
     pub fn with_justify(mut self, justify_text: JustifyText) -> Self {
         // TODO: Warn otherwise?
-        if let TooltipContent::Primary(text) = &mut self.content {
-            text.justify = justify_text;
+        match &mut self.content {
+            TooltipContent::Primary(text) => text.justify = justify_text,
+            TooltipContent::Slots(slots) => {
+                for (_, text) in slots {
+                    text.justify = justify_text;
+                }
+            }
+            TooltipContent::Custom(_) => {}
         }
         self
     }
 
+    /// Mirror the tooltip for right-to-left text.
+    ///
+    /// This right-justifies the text and mirrors the placement (see [`TooltipPlacement::mirrored`]),
+    /// so it should be called after setting a custom placement.
+    ///
+    /// NOTE: The text justification does nothing for custom tooltips.
+    pub fn with_rtl(self) -> Self {
+        let placement = self.placement.mirrored();
+        self.with_justify(JustifyText::Right)
+            .with_placement(placement)
+    }
+
+    /// Set the [`TooltipContent`] to display while waiting for the activation delay.
+    pub fn with_delayed_content(mut self, content: impl Into<TooltipContent>) -> Self {
+        self.delayed_content = Some(content.into());
+        self
+    }
+
     /// Set a custom [`TooltipPlacement`].
     pub fn with_placement(mut self, placement: impl Into<TooltipPlacement>) -> Self {
         self.placement = placement.into();
@@ -317,6 +740,124 @@ impl Tooltip {
         self.transfer = transfer.into();
         self
     }
+
+    /// Set the [activation delay](TooltipActivation::delay) (in milliseconds).
+    ///
+    /// ```
+    /// # use pyri_tooltip::prelude::*;
+    /// let tooltip = Tooltip::cursor("Hello, world!").with_delay_ms(300);
+    /// assert_eq!(tooltip.activation.delay, 300);
+    /// ```
+    pub fn with_delay_ms(mut self, delay: u16) -> Self {
+        self.activation.delay = delay;
+        self
+    }
+
+    /// Set the [dismissal distance](TooltipDismissal::on_distance).
+    ///
+    /// ```
+    /// # use pyri_tooltip::prelude::*;
+    /// let tooltip = Tooltip::cursor("Hello, world!")
+    ///     .with_delay_ms(300)
+    ///     .with_dismiss_distance(64.0);
+    /// assert_eq!(tooltip.dismissal.on_distance, DismissDistance::Pixels(64.0));
+    /// ```
+    pub fn with_dismiss_distance(mut self, distance: impl Into<DismissDistance>) -> Self {
+        self.dismissal.on_distance = distance.into();
+        self
+    }
+
+    /// Set the [transfer group](TooltipTransfer::group).
+    ///
+    /// ```
+    /// # use bevy::sprite::Anchor;
+    /// # use pyri_tooltip::prelude::*;
+    /// let tooltip = Tooltip::fixed(Anchor::TopCenter, "Hello, world!")
+    ///     .with_delay_ms(300)
+    ///     .with_dismiss_distance(64.0)
+    ///     .with_transfer_group(1);
+    /// assert_eq!(tooltip.transfer.group, Some(1));
+    /// ```
+    pub fn with_transfer_group(mut self, group: i8) -> Self {
+        self.transfer.group = Some(group);
+        self
+    }
+
+    /// Color the tooltip text with the target entity's [`TextColor`](bevy_text::TextColor).
+    ///
+    /// NOTE: This does nothing for custom tooltips.
+    pub fn with_inherit_text_color(mut self) -> Self {
+        self.inherit_text_color = true;
+        self
+    }
+
+    /// Set the [max width](Self::max_width).
+    pub fn with_max_width(mut self, max_width: TooltipMaxWidth) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Set the [auto-fit font size range](Self::autofit).
+    pub fn with_autofit(mut self, autofit: AutoFit) -> Self {
+        self.autofit = Some(autofit);
+        self
+    }
+
+    /// Set the [max lines](Self::max_lines).
+    pub fn with_max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    /// Set the [z-index](Self::z_index).
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.z_index = Some(z_index);
+        self
+    }
+
+    /// Set the [eligibility condition](Self::enabled_when).
+    pub fn with_enabled_when(mut self, enabled_when: fn(&World) -> bool) -> Self {
+        self.enabled_when = Some(enabled_when);
+        self
+    }
+
+    /// Set the [distance dismissal suppression condition](Self::suppress_distance_dismiss_when).
+    pub fn with_suppress_distance_dismiss_when(mut self, suppress: fn(&World) -> bool) -> Self {
+        self.suppress_distance_dismiss_when = Some(suppress);
+        self
+    }
+
+    /// Set the [time source](Self::time_source).
+    pub fn with_time_source(mut self, time_source: TimeSource) -> Self {
+        self.time_source = time_source;
+        self
+    }
+
+    /// Set the name of the [preset](Self::preset) to apply.
+    ///
+    /// ```
+    /// # use pyri_tooltip::prelude::*;
+    /// let tooltip = Tooltip::cursor("Hello, world!").preset("danger");
+    /// assert_eq!(tooltip.preset.as_deref(), Some("danger"));
+    /// ```
+    pub fn preset(mut self, preset: impl Into<String>) -> Self {
+        self.preset = Some(preset.into());
+        self
+    }
+
+    /// Set the [show sound](Self::show_sound).
+    #[cfg(feature = "audio")]
+    pub fn with_show_sound(mut self, sound: Handle<AudioSource>) -> Self {
+        self.show_sound = Some(sound);
+        self
+    }
+
+    /// Set the [hide sound](Self::hide_sound).
+    #[cfg(feature = "audio")]
+    pub fn with_hide_sound(mut self, sound: Handle<AudioSource>) -> Self {
+        self.hide_sound = Some(sound);
+        self
+    }
 }
 
 /// Tooltip content to be displayed.
@@ -325,10 +866,149 @@ impl Tooltip {
 pub enum TooltipContent {
     /// Display the primary tooltip with custom [`RichText`].
     Primary(RichText),
+    /// Display the primary tooltip with custom [`RichText`] in each named [`TooltipTextSlot`].
+    ///
+    /// This can be used for tabular tooltips, such as a column of labels next to a column of
+    /// values.
+    Slots(Vec<(String, RichText)>),
     /// Display a fully custom entity as the tooltip.
     Custom(Entity),
 }
 
+impl TooltipContent {
+    /// Create [`Self::Slots`] from a list of slot names and their text.
+    pub fn slots(
+        slots: impl IntoIterator<Item = (impl Into<String>, impl Into<RichText>)>,
+    ) -> Self {
+        Self::Slots(
+            slots
+                .into_iter()
+                .map(|(name, text)| (name.into(), text.into()))
+                .collect(),
+        )
+    }
+
+    /// Create [`Self::Primary`] from a list of lines.
+    ///
+    /// Each line is a separate [`TextSection`] so that it can be styled individually. Every line
+    /// except the last ends with a newline.
+    ///
+    /// ```
+    /// # use pyri_tooltip::prelude::*;
+    /// let TooltipContent::Primary(text) = TooltipContent::lines(["Sword", "Damage: 12"]) else {
+    ///     unreachable!();
+    /// };
+    /// assert_eq!(text.sections.len(), 2);
+    /// assert_eq!(text.sections[0].value, "Sword\n");
+    /// assert_eq!(text.sections[1].value, "Damage: 12");
+    /// ```
+    pub fn lines(lines: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let mut sections = lines
+            .into_iter()
+            .map(|line| {
+                let mut line = line.into();
+                line.push('\n');
+                TextSection::new(line, TextStyle::default())
+            })
+            .collect::<Vec<_>>();
+        if let Some(last) = sections.last_mut() {
+            last.value.pop();
+        }
+        Self::Primary(RichText::from_sections(sections))
+    }
+
+    /// Create [`Self::Primary`] from a bulleted or numbered list.
+    ///
+    /// Each item is a separate line, as in [`Self::lines`], and each of its sub-items is an
+    /// indented line below it.
+    ///
+    /// ```
+    /// # use pyri_tooltip::prelude::*;
+    /// let content = TooltipContent::list(
+    ///     [
+    ///         TooltipListItem::from("Fire damage"),
+    ///         TooltipListItem::new("Burns on hit").with_sub_items(["3 seconds"]),
+    ///     ],
+    ///     true,
+    /// );
+    /// let TooltipContent::Primary(text) = content else {
+    ///     unreachable!();
+    /// };
+    /// let text = text.sections.iter().map(|x| x.value.as_str()).collect::<String>();
+    /// assert_eq!(text, "1. Fire damage\n2. Burns on hit\n    a. 3 seconds");
+    /// ```
+    pub fn list(
+        items: impl IntoIterator<Item = impl Into<TooltipListItem>>,
+        ordered: bool,
+    ) -> Self {
+        let mut lines = Vec::new();
+        for (i, item) in items.into_iter().enumerate() {
+            let item = item.into();
+            lines.push(if ordered {
+                format!("{}. {}", i + 1, item.text)
+            } else {
+                format!("\u{2022} {}", item.text)
+            });
+            for (j, sub_item) in item.sub_items.into_iter().enumerate() {
+                lines.push(if ordered {
+                    let letter = char::from_u32('a' as u32 + j as u32 % 26).unwrap_or('a');
+                    format!("    {letter}. {sub_item}")
+                } else {
+                    format!("    \u{25e6} {sub_item}")
+                });
+            }
+        }
+        Self::lines(lines)
+    }
+
+    fn entity(&self, primary: &TooltipSettings) -> Entity {
+        match *self {
+            Self::Primary(_) | Self::Slots(_) => primary.container,
+            Self::Custom(id) => id,
+        }
+    }
+}
+
+/// An item in a [list tooltip](TooltipContent::list), with optional sub-items nested one level.
+#[derive(Clone, Default, Debug)]
+pub struct TooltipListItem {
+    /// The text of the item.
+    pub text: String,
+    /// The text of each sub-item.
+    pub sub_items: Vec<String>,
+}
+
+impl TooltipListItem {
+    /// Create a new `TooltipListItem` without sub-items.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            sub_items: Vec::new(),
+        }
+    }
+
+    /// Set the sub-items.
+    pub fn with_sub_items(
+        mut self,
+        sub_items: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.sub_items = sub_items.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl From<&str> for TooltipListItem {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for TooltipListItem {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
 impl From<&str> for TooltipContent {
     fn from(value: &str) -> Self {
         Self::Primary(RichText::from_section(
@@ -346,7 +1026,7 @@ impl From<String> for TooltipContent {
 
 impl From<TextSection> for TooltipContent {
     fn from(value: TextSection) -> Self {
-        Self::Primary(RichText::from_section(value.value, value.style))
+        Self::Primary(RichText::from_sections([value]))
     }
 }
 
@@ -371,64 +1051,195 @@ impl From<Entity> for TooltipContent {
 /// Tooltip activation conditions.
 ///
 /// Defaults to [`Self::IMMEDIATE`].
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 pub struct TooltipActivation {
     /// The hover duration before the tooltip will activate (in milliseconds).
     pub delay: u16,
-    /// Whether to reset the activation delay timer whenever the cursor moves.
-    pub reset_delay_on_cursor_move: bool,
+    /// How the activation delay timer responds to cursor movement.
+    pub delay_mode: DelayMode,
+    /// A mouse button that must be held for the tooltip to be active, or `None` to disable.
+    ///
+    /// The tooltip will be hidden when the button is released, and shown again when it's pressed.
+    pub hold_button: Option<MouseButton>,
+    /// The duration the tooltip will stay active after the cursor leaves the target (in
+    /// milliseconds).
+    pub hide_delay: u16,
+    /// The duration after the tooltip was last shown within which [`Self::recent_delay`] will be
+    /// used instead of [`Self::delay`] (in milliseconds), or `0` to disable.
+    pub recent_window: u16,
+    /// The activation delay to use if the tooltip was shown recently (in milliseconds).
+    pub recent_delay: u16,
+    /// The direction the cursor must be moving in when it enters the target, or `None` to allow
+    /// any direction.
+    ///
+    /// The direction is in viewport coordinates, so [`Dir2::Y`] means the cursor is moving down
+    /// and entering from the top. The cursor's last movement direction must be within 45 degrees
+    /// of this direction; otherwise, the target entity is treated as if it had no tooltip until
+    /// the cursor enters it again.
+    pub enter_direction: Option<Dir2>,
+    /// Whether to skip the activation delay if the target entity is spawned under the cursor.
+    ///
+    /// The target entity counts as spawned under the cursor if the cursor hasn't moved between
+    /// its [`Tooltip`] being added and it being hovered.
+    pub immediate_on_spawn: bool,
+    /// Whether the cursor must stay inside the target entity for the entire activation delay.
+    ///
+    /// If enabled, leaving the target entity at any point during the delay (even momentarily)
+    /// cancels activation, and re-entering it will start the delay over.
+    pub strict_continuous: bool,
 }
 
 impl TooltipActivation {
     /// Show tooltip immediately on hover.
     pub const IMMEDIATE: Self = Self {
         delay: 0,
-        reset_delay_on_cursor_move: false,
+        delay_mode: DelayMode::Ignore,
+        hold_button: None,
+        hide_delay: 0,
+        recent_window: 0,
+        recent_delay: 0,
+        enter_direction: None,
+        immediate_on_spawn: false,
+        strict_continuous: false,
     };
 
     /// Show tooltip after a short while.
     pub const SHORT_DELAY: Self = Self {
         delay: 200,
-        reset_delay_on_cursor_move: false,
+        delay_mode: DelayMode::Ignore,
+        hold_button: None,
+        hide_delay: 0,
+        recent_window: 0,
+        recent_delay: 0,
+        enter_direction: None,
+        immediate_on_spawn: false,
+        strict_continuous: false,
     };
 
     /// Show tooltip after a while.
     pub const DELAY: Self = Self {
         delay: 400,
-        reset_delay_on_cursor_move: false,
+        delay_mode: DelayMode::Ignore,
+        hold_button: None,
+        hide_delay: 0,
+        recent_window: 0,
+        recent_delay: 0,
+        enter_direction: None,
+        immediate_on_spawn: false,
+        strict_continuous: false,
     };
 
     /// Show tooltip after a long while.
     pub const LONG_DELAY: Self = Self {
         delay: 600,
-        reset_delay_on_cursor_move: false,
+        delay_mode: DelayMode::Ignore,
+        hold_button: None,
+        hide_delay: 0,
+        recent_window: 0,
+        recent_delay: 0,
+        enter_direction: None,
+        immediate_on_spawn: false,
+        strict_continuous: false,
     };
 
     /// Show tooltip after the cursor stays idle for a short while.
     pub const SHORT_IDLE: Self = Self {
         delay: 200,
-        reset_delay_on_cursor_move: true,
+        delay_mode: DelayMode::Reset,
+        hold_button: None,
+        hide_delay: 0,
+        recent_window: 0,
+        recent_delay: 0,
+        enter_direction: None,
+        immediate_on_spawn: false,
+        strict_continuous: false,
     };
 
     /// Show tooltip after the cursor stays idle for a while.
     pub const IDLE: Self = Self {
         delay: 400,
-        reset_delay_on_cursor_move: true,
+        delay_mode: DelayMode::Reset,
+        hold_button: None,
+        hide_delay: 0,
+        recent_window: 0,
+        recent_delay: 0,
+        enter_direction: None,
+        immediate_on_spawn: false,
+        strict_continuous: false,
     };
 
     /// Show tooltip after the cursor stays idle for a long while.
     pub const LONG_IDLE: Self = Self {
         delay: 600,
-        reset_delay_on_cursor_move: true,
+        delay_mode: DelayMode::Reset,
+        hold_button: None,
+        hide_delay: 0,
+        recent_window: 0,
+        recent_delay: 0,
+        enter_direction: None,
+        immediate_on_spawn: false,
+        strict_continuous: false,
     };
+
+    /// Show tooltip only while a mouse button is held on hover.
+    pub const fn hold(button: MouseButton) -> Self {
+        Self {
+            delay: 0,
+            delay_mode: DelayMode::Ignore,
+            hold_button: Some(button),
+            hide_delay: 0,
+            recent_window: 0,
+            recent_delay: 0,
+            enter_direction: None,
+            immediate_on_spawn: false,
+            strict_continuous: false,
+        }
+    }
+}
+
+/// How a tooltip's activation delay timer responds to cursor movement.
+///
+/// Defaults to [`Self::Ignore`].
+#[derive(Copy, Clone, Eq, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum DelayMode {
+    /// Count down the activation delay regardless of cursor movement.
+    #[default]
+    Ignore,
+    /// Pause the activation delay while the cursor is moving, and resume it when the cursor is
+    /// still.
+    Pause,
+    /// Restart the activation delay whenever the cursor moves.
+    Reset,
+}
+
+/// The clock used by a tooltip's timers, such as its activation delay and transfer timeout.
+///
+/// Defaults to [`Self::Virtual`].
+#[derive(Copy, Clone, Eq, PartialEq, Default, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum TimeSource {
+    /// Use virtual time, which is affected by pausing and relative speed (e.g. for gameplay
+    /// tooltips).
+    #[default]
+    Virtual,
+    /// Use real time, which is unaffected by pausing and relative speed (e.g. for UI tooltips).
+    Real,
 }
 
 impl From<u16> for TooltipActivation {
     fn from(value: u16) -> Self {
         Self {
             delay: value,
-            reset_delay_on_cursor_move: false,
+            delay_mode: DelayMode::Ignore,
+            hold_button: None,
+            hide_delay: 0,
+            recent_window: 0,
+            recent_delay: 0,
+            enter_direction: None,
+            immediate_on_spawn: false,
+            strict_continuous: false,
         }
     }
 }
@@ -446,22 +1257,33 @@ impl Default for TooltipActivation {
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 pub struct TooltipDismissal {
     /// The distance from the activation point beyond which the tooltip will be dismissed.
-    pub on_distance: f32,
-    /// Whether the tooltip should be dismissed on click.
+    pub on_distance: DismissDistance,
+    /// Whether to only dismiss beyond [`Self::on_distance`] while the cursor is moving away from
+    /// the center of the target entity.
+    ///
+    /// NOTE: This only applies to UI node targets.
+    pub directional: bool,
+    /// Whether the tooltip should be dismissed on left click.
     pub on_click: bool,
+    /// When a tooltip dismissed on click can be reactivated.
+    pub reactivate: ReactivatePolicy,
 }
 
 impl TooltipDismissal {
     /// No tooltip dismissal.
     pub const NONE: Self = Self {
-        on_distance: f32::INFINITY,
+        on_distance: DismissDistance::Pixels(f32::INFINITY),
+        directional: false,
         on_click: false,
+        reactivate: ReactivatePolicy::OnReenter,
     };
 
-    /// Dismiss tooltip on click.
+    /// Dismiss tooltip on left click.
     pub const ON_CLICK: Self = Self {
-        on_distance: f32::INFINITY,
+        on_distance: DismissDistance::Pixels(f32::INFINITY),
+        directional: false,
         on_click: true,
+        reactivate: ReactivatePolicy::OnReenter,
     };
 }
 
@@ -471,6 +1293,150 @@ impl Default for TooltipDismissal {
     }
 }
 
+/// The [max width](Tooltip::max_width) of a tooltip.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum TooltipMaxWidth {
+    /// A fixed width in logical pixels.
+    Pixels(f32),
+    /// A multiple of the target's width, resolved on activation.
+    ///
+    /// If the target's size is unknown (e.g. its sprite image hasn't loaded), the container's own
+    /// max width is kept instead.
+    RelativeToTarget(f32),
+}
+
+impl TooltipMaxWidth {
+    /// Resolve to a width in logical pixels for a target of the given width.
+    ///
+    /// ```
+    /// # use pyri_tooltip::TooltipMaxWidth;
+    /// let max_width = TooltipMaxWidth::RelativeToTarget(2.0);
+    /// assert_eq!(max_width.resolve(50.0), 100.0);
+    /// assert_eq!(max_width.resolve(120.0), 240.0);
+    /// assert_eq!(TooltipMaxWidth::Pixels(200.0).resolve(50.0), 200.0);
+    /// ```
+    pub fn resolve(self, target_width: f32) -> f32 {
+        match self {
+            Self::Pixels(width) => width,
+            Self::RelativeToTarget(factor) => factor * target_width,
+        }
+    }
+}
+
+/// The font size range for a tooltip with [auto-fit](Tooltip::autofit) text.
+///
+/// The text starts at the max font size and shrinks until it fits within the content box of the
+/// primary tooltip container, down to the min font size. This is intended for a container with a
+/// fixed size, such as a HUD panel displaying localized strings of varying length.
+///
+/// Each shrink step waits for the text to be laid out again, so the text may take a few frames to
+/// settle.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct AutoFit {
+    /// The smallest font size to shrink to.
+    pub min_font: f32,
+    /// The font size to start from.
+    pub max_font: f32,
+}
+
+/// When a tooltip [dismissed on click](TooltipDismissal::on_click) can be reactivated.
+///
+/// Defaults to [`Self::OnReenter`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum ReactivatePolicy {
+    /// Reactivate only after the cursor leaves the target entity and returns.
+    #[default]
+    OnReenter,
+    /// Reactivate as soon as the cursor moves over the target entity.
+    Immediate,
+    /// Reactivate after the cursor has stayed over the target entity for a cooldown (in
+    /// milliseconds).
+    Cooldown(u16),
+}
+
+/// The [dismissal distance](TooltipDismissal::on_distance) of a tooltip.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum DismissDistance {
+    /// A fixed distance in logical pixels.
+    Pixels(f32),
+    /// A multiple of the target's half-extent along its larger axis.
+    ///
+    /// For example, `RelativeToTarget(1.5)` on a 100x40 target resolves to 75 pixels. If the
+    /// target's size is unknown (e.g. its sprite image hasn't loaded), 64 pixels is used instead.
+    RelativeToTarget(f32),
+    /// A fixed distance in world units, converted to logical pixels using the camera's current
+    /// scale at the target entity's position.
+    ///
+    /// This keeps the dismissal radius of a sprite tooltip consistent as the camera zooms. For UI
+    /// targets, a world unit is a logical pixel.
+    WorldUnits(f32),
+}
+
+impl DismissDistance {
+    /// Resolve to a distance in logical pixels for a target of the given size.
+    ///
+    /// ```
+    /// # use bevy::math::Vec2;
+    /// # use pyri_tooltip::DismissDistance;
+    /// let small = Vec2::new(20.0, 10.0);
+    /// let large = Vec2::new(200.0, 100.0);
+    ///
+    /// // An absolute radius is independent of the target's size.
+    /// let absolute = DismissDistance::Pixels(32.0);
+    /// assert_eq!(absolute.resolve(small), 32.0);
+    /// assert_eq!(absolute.resolve(large), 32.0);
+    ///
+    /// // A relative radius scales with the target's size.
+    /// let relative = DismissDistance::RelativeToTarget(1.5);
+    /// assert_eq!(relative.resolve(small), 15.0);
+    /// assert_eq!(relative.resolve(large), 150.0);
+    /// ```
+    pub fn resolve(self, target_size: Vec2) -> f32 {
+        self.resolve_scaled(target_size, 1.0)
+    }
+
+    /// Resolve to a distance in logical pixels for a target of the given size, with the given
+    /// number of logical pixels per world unit.
+    ///
+    /// ```
+    /// # use bevy::math::Vec2;
+    /// # use pyri_tooltip::DismissDistance;
+    /// let size = Vec2::new(20.0, 10.0);
+    ///
+    /// // A world-unit radius grows on screen as the camera zooms in.
+    /// let world = DismissDistance::WorldUnits(32.0);
+    /// assert_eq!(world.resolve_scaled(size, 1.0), 32.0);
+    /// assert_eq!(world.resolve_scaled(size, 2.0), 64.0);
+    ///
+    /// // Other distances are unaffected.
+    /// let absolute = DismissDistance::Pixels(32.0);
+    /// assert_eq!(absolute.resolve_scaled(size, 2.0), 32.0);
+    /// ```
+    pub fn resolve_scaled(self, target_size: Vec2, pixels_per_unit: f32) -> f32 {
+        match self {
+            Self::Pixels(distance) => distance,
+            Self::RelativeToTarget(factor) => factor * 0.5 * target_size.max_element(),
+            Self::WorldUnits(distance) => distance * pixels_per_unit,
+        }
+    }
+}
+
+impl Default for DismissDistance {
+    fn default() -> Self {
+        Self::Pixels(f32::INFINITY)
+    }
+}
+
+impl From<f32> for DismissDistance {
+    fn from(value: f32) -> Self {
+        Self::Pixels(value)
+    }
+}
+
 /// Tooltip transfer conditions.
 ///
 /// When a transfer occurs, the next tooltip's [activation delay](TooltipActivation::delay) will be skipped.
@@ -486,7 +1452,22 @@ pub struct TooltipTransfer {
     /// Only transfer within this duration after the cursor moves away from the old target (in milliseconds).
     pub timeout: u16,
     /// Only transfer if the old tooltip was active.
+    ///
+    /// This only affects a tooltip that was still delayed. A dismissed tooltip is controlled by
+    /// [`Self::dismiss_starts_transfer`] instead.
     pub from_active: bool,
+    /// Whether dismissing the old tooltip (e.g. by click) still allows a transfer.
+    ///
+    /// If `false`, a dismissed tooltip fully resets to inactive without a transfer timeout.
+    ///
+    /// NOTE: A dismissed tooltip used to start a transfer timeout if [`Self::from_active`] was
+    /// `false`. Set this to `!from_active` to keep that behavior.
+    pub dismiss_starts_transfer: bool,
+    /// Whether to never skip this tooltip's activation delay via transfer.
+    ///
+    /// If `true`, this tooltip always waits for its full activation delay, even when the cursor
+    /// moves to it from a tooltip that would otherwise transfer.
+    pub no_transfer_in: bool,
 }
 
 impl TooltipTransfer {
@@ -496,6 +1477,8 @@ impl TooltipTransfer {
         layer: 0,
         timeout: 0,
         from_active: true,
+        dismiss_starts_transfer: false,
+        no_transfer_in: false,
     };
 
     /// Short-duration tooltip transfer.
@@ -504,6 +1487,8 @@ impl TooltipTransfer {
         layer: 0,
         timeout: 100,
         from_active: true,
+        dismiss_starts_transfer: false,
+        no_transfer_in: false,
     };
 }
 
@@ -514,6 +1499,8 @@ impl From<u16> for TooltipTransfer {
             layer: 0,
             timeout: value,
             from_active: true,
+            dismiss_starts_transfer: false,
+            no_transfer_in: false,
         }
     }
 }
@@ -525,10 +1512,86 @@ impl Default for TooltipTransfer {
 }
 
 /// A [`SystemSet`] for tooltip systems.
+///
+/// Each frame, the tooltip systems run in the following order:
+///
+/// 1. [`Self::Detection`] determines the hovered target and updates the tooltip state.
+/// 2. [`Self::Content`] shows / hides the tooltip content based on the new state.
+/// 3. [`Self::Placement`] positions the tooltip after UI layout.
 #[derive(SystemSet, Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum TooltipSystems {
+    /// Detect the hovered target and update the tooltip state (runs in [`PreUpdate`]).
+    Detection,
     /// Update and show / hide the tooltip content (runs in [`PreUpdate`]).
     Content,
     /// Position the tooltip using its calculated size (runs in [`PostUpdate`]).
     Placement,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restore_settings_snapshot() {
+        let mut world = World::new();
+        let mut settings =
+            TooltipSettings::new(&mut world, Entity::PLACEHOLDER, Entity::PLACEHOLDER, true);
+        let snapshot = settings.snapshot();
+
+        settings.enabled = false;
+        settings.scale = 2.0;
+        settings.delay_scale = 0.5;
+        settings.fallback = Some(FallbackTooltip::default());
+        settings.backdrop = Some(TooltipBackdrop::default());
+        assert_ne!(settings, snapshot);
+
+        settings.restore(snapshot);
+        assert_eq!(settings, snapshot);
+    }
+
+    #[test]
+    fn rtl_mirrors_placement() {
+        let placement = TooltipPlacement {
+            tooltip_anchor: Anchor::TopLeft,
+            target_anchor: Some(Anchor::BottomLeft),
+            offset_x: Val::Px(16.0),
+            offset_y: Val::Px(8.0),
+            clamp_padding: UiRect::new(Val::Px(4.0), Val::Px(12.0), Val::ZERO, Val::ZERO),
+            ..TooltipPlacement::CURSOR
+        };
+        let tooltip = Tooltip::cursor("Hello, world!")
+            .with_placement(placement)
+            .with_rtl();
+
+        assert_eq!(
+            tooltip.placement,
+            TooltipPlacement {
+                tooltip_anchor: Anchor::TopRight,
+                target_anchor: Some(Anchor::BottomRight),
+                offset_x: Val::Px(-16.0),
+                offset_y: Val::Px(8.0),
+                clamp_padding: UiRect::new(Val::Px(12.0), Val::Px(4.0), Val::ZERO, Val::ZERO),
+                ..TooltipPlacement::CURSOR
+            },
+        );
+        let TooltipContent::Primary(text) = &tooltip.content else {
+            panic!("expected primary tooltip content");
+        };
+        assert_eq!(text.justify, JustifyText::Right);
+
+        // Centered anchors are unchanged, and custom anchors and screen positions are mirrored.
+        let placement = TooltipPlacement {
+            tooltip_anchor: Anchor::TopCenter,
+            target_anchor: Some(Anchor::Custom(Vec2::new(0.25, 0.5))),
+            ..TooltipPlacement::normalized_screen(Vec2::new(0.2, 0.9))
+        }
+        .mirrored();
+        assert_eq!(placement.tooltip_anchor, Anchor::TopCenter);
+        assert_eq!(
+            placement.target_anchor,
+            Some(Anchor::Custom(Vec2::new(-0.25, 0.5))),
+        );
+        assert_eq!(placement.screen_position, Some(Vec2::new(0.8, 0.9)));
+    }
+}
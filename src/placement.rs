@@ -1,11 +1,26 @@
+use alloc::vec::Vec;
+use core::time::Duration;
+
 use bevy_app::{App, PostUpdate};
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::{ReflectComponent, ReflectResource};
 use bevy_ecs::{
+    change_detection::DetectChanges as _,
+    component::Component,
+    entity::Entity,
+    hierarchy::{ChildOf, Children},
+    observer::Trigger,
+    relationship::RelationshipTarget as _,
+    resource::Resource,
     schedule::IntoScheduleConfigs as _,
-    system::{Commands, Query, Res},
+    system::{Commands, Local, Query, Res, SystemParam},
+    world::{DeferredWorld, OnDespawn},
 };
-use bevy_math::{Rect, Vec2};
-use bevy_render::camera::Camera;
-use bevy_sprite::{Anchor, Sprite};
+use bevy_math::{Rect, Vec2, ops};
+use bevy_render::{camera::Camera, view::Visibility};
+use bevy_sprite::Anchor;
+use bevy_text::TextLayoutInfo;
+use bevy_time::Time;
 use bevy_transform::{
     components::{GlobalTransform, Transform},
     systems::{mark_dirty_trees, propagate_parent_transforms, sync_simple_transforms},
@@ -14,14 +29,22 @@ use bevy_ui::{ComputedNode, DefaultUiCamera, Node, UiRect, UiTargetCamera, Val};
 use tiny_bail::prelude::*;
 
 use crate::{
-    TooltipContent, TooltipSettings, TooltipSystems,
-    context::{TooltipContext, TooltipState},
+    TooltipContent, TooltipSettings, TooltipSystems, WorldTooltip,
+    context::{TargetQuery, TooltipContext},
+    rich_text::RichText,
 };
 
 pub(super) fn plugin(app: &mut App) {
+    #[cfg(feature = "bevy_reflect")]
+    app.register_type::<TooltipPivot>();
+    #[cfg(feature = "bevy_reflect")]
+    app.register_type::<TooltipAvoidRects>();
+    app.init_resource::<TooltipAvoidRects>();
+    app.add_observer(detach_tooltip_on_despawn);
     app.add_systems(
         PostUpdate,
         (
+            fit_tooltip_text,
             place_tooltip,
             mark_dirty_trees,
             propagate_parent_transforms,
@@ -35,7 +58,7 @@ pub(super) fn plugin(app: &mut App) {
 /// The tooltip placement configuration.
 ///
 /// Defaults to [`Self::CURSOR_CENTERED`].
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 pub struct TooltipPlacement {
     /// The anchor point on the tooltip entity.
@@ -48,6 +71,26 @@ pub struct TooltipPlacement {
     pub offset_y: Val,
     /// Clamp the tooltip entity within the window with additional padding.
     pub clamp_padding: UiRect,
+    /// A rect to clamp the tooltip entity within instead of the window (in viewport coordinates),
+    /// or `None` to use the window.
+    ///
+    /// This can be used to keep a tooltip that follows the cursor inside a panel.
+    pub bounds: Option<Rect>,
+    /// The target position in normalized viewport coordinates, or `None` to use the target
+    /// entity or cursor position instead.
+    ///
+    /// `(0.0, 0.0)` is the top left corner and `(1.0, 1.0)` is the bottom right corner of the
+    /// viewport, so the position is independent of resolution. This overrides
+    /// [`Self::target_anchor`].
+    pub screen_position: Option<Vec2>,
+    /// Parent the tooltip entity to the target entity while the tooltip is active.
+    ///
+    /// This only applies to UI targets; other targets fall back to placement in screen space. The
+    /// tooltip entity is returned to its original parent when the tooltip is hidden or the target
+    /// is despawned.
+    ///
+    /// Note that the target should not clip its children.
+    pub attach_to_target: bool,
 }
 
 impl TooltipPlacement {
@@ -58,6 +101,9 @@ impl TooltipPlacement {
         offset_x: Val::ZERO,
         offset_y: Val::ZERO,
         clamp_padding: UiRect::ZERO,
+        bounds: None,
+        screen_position: None,
+        attach_to_target: false,
     };
 
     /// Show tooltip at cursor.
@@ -67,7 +113,80 @@ impl TooltipPlacement {
         offset_x: Val::Px(16.0),
         offset_y: Val::Px(16.0),
         clamp_padding: UiRect::ZERO,
+        bounds: None,
+        screen_position: None,
+        attach_to_target: false,
     };
+
+    /// Show tooltip as a child of the target UI node, offset from its top left corner.
+    ///
+    /// See [`Self::attach_to_target`] for details.
+    pub const fn child_of_target(offset: Vec2) -> Self {
+        Self {
+            tooltip_anchor: Anchor::TopLeft,
+            target_anchor: Some(Anchor::TopLeft),
+            offset_x: Val::Px(offset.x),
+            offset_y: Val::Px(offset.y),
+            clamp_padding: UiRect::ZERO,
+            bounds: None,
+            screen_position: None,
+            attach_to_target: true,
+        }
+    }
+
+    /// Show tooltip centered at a position in normalized viewport coordinates (e.g. for a HUD
+    /// hint).
+    ///
+    /// See [`Self::screen_position`] for details.
+    pub const fn normalized_screen(pos: Vec2) -> Self {
+        Self {
+            tooltip_anchor: Anchor::Center,
+            target_anchor: None,
+            offset_x: Val::ZERO,
+            offset_y: Val::ZERO,
+            clamp_padding: UiRect::ZERO,
+            bounds: None,
+            screen_position: Some(pos),
+            attach_to_target: false,
+        }
+    }
+
+    /// Mirror the placement horizontally for right-to-left layouts.
+    ///
+    /// The left and right anchors are swapped (e.g. [`Anchor::TopLeft`] becomes
+    /// [`Anchor::TopRight`]), and the horizontal offset is negated. The center, top center, and
+    /// bottom center anchors are unchanged.
+    pub fn mirrored(self) -> Self {
+        Self {
+            tooltip_anchor: mirror_anchor(self.tooltip_anchor),
+            target_anchor: self.target_anchor.map(mirror_anchor),
+            offset_x: -self.offset_x,
+            offset_y: self.offset_y,
+            clamp_padding: UiRect {
+                left: self.clamp_padding.right,
+                right: self.clamp_padding.left,
+                ..self.clamp_padding
+            },
+            bounds: self.bounds,
+            screen_position: self
+                .screen_position
+                .map(|pos| Vec2::new(1.0 - pos.x, pos.y)),
+            attach_to_target: self.attach_to_target,
+        }
+    }
+}
+
+fn mirror_anchor(anchor: Anchor) -> Anchor {
+    match anchor {
+        Anchor::BottomLeft => Anchor::BottomRight,
+        Anchor::BottomRight => Anchor::BottomLeft,
+        Anchor::CenterLeft => Anchor::CenterRight,
+        Anchor::CenterRight => Anchor::CenterLeft,
+        Anchor::TopLeft => Anchor::TopRight,
+        Anchor::TopRight => Anchor::TopLeft,
+        Anchor::Custom(x) => Anchor::Custom(Vec2::new(-x.x, x.y)),
+        x => x,
+    }
 }
 
 impl From<Anchor> for TooltipPlacement {
@@ -88,6 +207,9 @@ impl From<Anchor> for TooltipPlacement {
             offset_x: Val::ZERO,
             offset_y: Val::ZERO,
             clamp_padding: UiRect::ZERO,
+            bounds: None,
+            screen_position: None,
+            attach_to_target: false,
         }
     }
 }
@@ -100,6 +222,9 @@ impl From<Vec2> for TooltipPlacement {
             offset_x: Val::Px(value.x),
             offset_y: Val::Px(value.y),
             clamp_padding: UiRect::ZERO,
+            bounds: None,
+            screen_position: None,
+            attach_to_target: false,
         }
     }
 }
@@ -110,75 +235,520 @@ impl Default for TooltipPlacement {
     }
 }
 
+/// A [`Component`] that sets the pivot point of a tooltip entity, overriding
+/// [`TooltipPlacement::tooltip_anchor`].
+///
+/// The pivot is expressed as a fraction of the tooltip entity's size, where `(0.0, 0.0)` is the
+/// top left corner and `(1.0, 1.0)` is the bottom right corner. This is intended for custom
+/// tooltip entities.
+#[derive(Component, Copy, Clone, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Component)
+)]
+pub struct TooltipPivot(pub Vec2);
+
+impl TooltipPivot {
+    /// Pivot on the top left corner.
+    pub const TOP_LEFT: Self = Self(Vec2::ZERO);
+
+    /// Pivot on the center.
+    pub const CENTER: Self = Self(Vec2::splat(0.5));
+}
+
+/// A [`Resource`] containing "keep clear" rects that tooltips should not overlap, such as a
+/// minimap or an action bar.
+///
+/// The rects are in viewport coordinates, with the origin at the top left corner.
+///
+/// After the tooltip has been clamped within the window, it's nudged out of each overlapping
+/// rect in order by the shortest distance. A later nudge may move the tooltip back into an
+/// earlier rect or outside of the window, so the rects should not be placed too close together.
+#[derive(Resource, Clone, Default, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Resource)
+)]
+pub struct TooltipAvoidRects(pub Vec<Rect>);
+
+/// The maximum number of frames to spend shrinking [auto-fit](crate::Tooltip::autofit) text.
+const MAX_AUTOFIT_STEPS: u8 = 8;
+
+fn fit_tooltip_text(
+    ctx: Res<TooltipContext>,
+    primary: Res<TooltipSettings>,
+    container_query: Query<&ComputedNode>,
+    mut text_query: Query<(&mut RichText, &TextLayoutInfo)>,
+    mut steps: Local<u8>,
+) {
+    let autofit = rq!(ctx.tooltip.autofit);
+    rq!(matches!(
+        ctx.displayed_content(),
+        Some(TooltipContent::Primary(_))
+    ));
+    let (mut text, layout) = r!(text_query.get_mut(primary.text));
+    // Start over whenever the text is replaced, because that resets the font size. This system's
+    // own changes from its previous run don't count.
+    if text.is_changed() {
+        *steps = 0;
+    }
+    rq!(*steps < MAX_AUTOFIT_STEPS);
+
+    // Compare the text size to the container's content box (both in physical pixels).
+    let container = r!(container_query.get(primary.container));
+    let inset = container.content_inset();
+    let available =
+        container.size() - Vec2::new(inset.left + inset.right, inset.top + inset.bottom);
+    let fit = (available / layout.size.max(Vec2::ONE)).min_element();
+    rq!(fit < 1.0);
+
+    // Shrink the font size. The text area scales with the square of the font size when the
+    // text wraps, so the square root of the fit ratio avoids overshooting.
+    *steps += 1;
+    let min_font = autofit.min_font * primary.scale;
+    let factor = fit.sqrt().min(0.95);
+    for section in &mut text.sections {
+        section.style.font_size = (section.style.font_size * factor).max(min_font);
+    }
+}
+
 // TODO: Only run on `ShowTooltip` event OR if using target anchor + target has moved or resized.
 fn place_tooltip(
     mut commands: Commands,
     ctx: Res<TooltipContext>,
     primary: Res<TooltipSettings>,
-    target_query: Query<(&GlobalTransform, Option<&ComputedNode>, Option<&Sprite>)>,
-    target_camera_query: Query<&UiTargetCamera>,
-    default_ui_camera: DefaultUiCamera,
-    camera_query: Query<&Camera>,
-    mut tooltip_query: Query<(&mut Node, &mut Transform, &GlobalTransform, &ComputedNode)>,
+    avoid_rects: Res<TooltipAvoidRects>,
+    targets: TargetQuery,
+    tooltip_camera: TooltipCamera,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    mut tooltip_query: Query<(
+        &mut Node,
+        &mut Transform,
+        &GlobalTransform,
+        &ComputedNode,
+        Option<&TooltipPivot>,
+        Option<&ChildOf>,
+        Option<&mut Visibility>,
+    )>,
+    parent_query: Query<(&GlobalTransform, &ComputedNode)>,
+    time: Res<Time>,
+    mut follow: Local<CursorFollow>,
+    attached_query: Query<&AttachedTooltip>,
+    mut attached: Local<Option<Entity>>,
+    mut slide: Local<TransferSlide>,
+    mut unmeasured: Local<Option<Entity>>,
 ) {
-    rq!(matches!(ctx.state, TooltipState::Active));
-    let (target_gt, target_computed, target_sprite) = rq!(target_query.get(ctx.target));
-    let entity = match &ctx.tooltip.content {
-        TooltipContent::Primary(_) => primary.container,
-        &TooltipContent::Custom(id) => id,
+    let Some(content) = ctx.displayed_content() else {
+        follow.last = None;
+        slide.from = None;
+        *unmeasured = None;
+        detach_tooltip(&mut commands, &mut attached, &attached_query);
+        return;
     };
-    let (mut node, mut transform, gt, computed) = r!(tooltip_query.get_mut(entity));
+    let entity = content.entity(&primary);
+    // The target entity may not exist (e.g. for a `WorldTooltip`).
+    let target = targets.get(ctx.target);
+
+    // Attach the tooltip entity to a UI target, or restore its original parent otherwise.
+    let attach = ctx.tooltip.placement.attach_to_target
+        && matches!(target, Some((_, Some(_), _)))
+        && entity != ctx.target;
+    let attachment = attached.and_then(|target| attached_query.get(target).ok().copied());
+    if !attach || attachment.is_some_and(|attachment| attachment.tooltip != entity) {
+        detach_tooltip(&mut commands, &mut attached, &attached_query);
+    }
+
+    let (mut node, mut transform, gt, computed, pivot, parent, visibility) =
+        r!(tooltip_query.get_mut(entity));
+
+    // Keep the tooltip entity hidden until it has been measured, to avoid clamping and flipping it
+    // with a zero size.
+    if computed.size() == Vec2::ZERO {
+        if let Some(mut visibility) = visibility {
+            *visibility = Visibility::Hidden;
+            *unmeasured = Some(entity);
+        }
+        return;
+    }
+    if unmeasured.take() == Some(entity)
+        && let Some(mut visibility) = visibility
+    {
+        *visibility = Visibility::Visible;
+    }
+    if attach && parent.is_none_or(|parent| parent.parent() != ctx.target) {
+        // Keep the original parent when moving between target entities.
+        let original_parent = match attachment {
+            Some(attachment) if attachment.tooltip == entity => attachment.parent,
+            _ => parent.map(ChildOf::parent),
+        };
+        if let Some(old_target) = attached.replace(ctx.target)
+            && old_target != ctx.target
+            && let Ok(mut old_target) = commands.get_entity(old_target)
+        {
+            old_target.remove::<AttachedTooltip>();
+        }
+        commands.entity(entity).insert(ChildOf(ctx.target));
+        commands.entity(ctx.target).insert(AttachedTooltip {
+            tooltip: entity,
+            parent: original_parent,
+        });
+    }
 
     // Identify the target camera and viewport rect.
-    let camera_entity = r!(target_camera_query
-        .get(ctx.target)
-        .map(UiTargetCamera::entity)
-        .ok()
-        .or(default_ui_camera.get()));
-    let camera = r!(camera_query.get(camera_entity));
+    let camera_entity = r!(tooltip_camera.get(&ctx));
+    let (camera, camera_gt) = r!(camera_query.get(camera_entity));
     let viewport = r!(camera.physical_viewport_rect());
+    // Convert the cursor position from window coordinates to the camera's viewport coordinates.
+    let viewport_min = camera
+        .logical_viewport_rect()
+        .map_or(Vec2::ZERO, |rect| rect.min);
+    let viewport_cursor_pos = ctx.cursor_pos - viewport_min;
     // Insert instead of mutate because the tooltip entity might not spawn with a `UiTargetCamera` component.
     commands
         .entity(entity)
         .insert(UiTargetCamera(camera_entity));
 
-    let placement = &ctx.tooltip.placement;
+    // Override the tooltip anchor with the pivot, and scale the offset.
+    let mut placement = ctx.tooltip.placement;
+    if let Some(pivot) = pivot {
+        placement.tooltip_anchor = Anchor::Custom(Vec2::new(pivot.0.x - 0.5, 0.5 - pivot.0.y));
+    }
+    placement.offset_x *= primary.scale;
+    placement.offset_y *= primary.scale;
 
-    // Calculate target position.
-    let mut pos = if let Some(target_anchor) = placement.target_anchor {
-        // Calculate target rect based on whether it's a UI element or sprite
-        let target_rect = if let Some(computed_node) = target_computed {
+    // Calculate target rect.
+    let target_rect = if placement.target_anchor.is_some() {
+        // Calculate target rect based on whether it's a UI element, sprite, or `Mesh2d`
+        if let Some((target_gt, Some(computed_node), _)) = target {
             // UI element with ComputedNode
             Rect::from_center_size(target_gt.translation().truncate(), computed_node.size())
-        } else if let Some(sprite) = target_sprite {
-            // Sprite - convert world position to screen position
-            let world_pos = target_gt.translation();
-            let screen_pos = match camera.world_to_viewport(target_gt, world_pos) {
-                Ok(pos) => pos,
-                Err(_) => ctx.cursor_pos, // Fallback to cursor position if conversion fails
-            };
-
-            // Calculate sprite size and bounds
-            let sprite_size = sprite.custom_size.unwrap_or(Vec2::new(1.0, 1.0));
-            Rect::from_center_size(screen_pos, sprite_size)
+        } else if let Some((target_gt, None, sprite)) = target
+            // Treat a sprite whose size is unknown (e.g. its image hasn't loaded) as a point.
+            && let Some(bounds) = targets
+                .local_rect(ctx.target)
+                .or_else(|| sprite.map(|_| Rect::default()))
+        {
+            // Sprite or `Mesh2d` - convert the world space corners of its bounds to viewport
+            // positions, so the rect is correct for rotated targets, and for cameras that are
+            // scaled (e.g. zoomed orthographic projection), rotated, or offset.
+            [
+                bounds.min,
+                Vec2::new(bounds.max.x, bounds.min.y),
+                bounds.max,
+                Vec2::new(bounds.min.x, bounds.max.y),
+            ]
+            .into_iter()
+            .try_fold(Rect::EMPTY, |rect, corner| {
+                let corner = target_gt.transform_point(corner.extend(0.0));
+                // `Camera::world_to_viewport` returns window coordinates.
+                let corner = camera.world_to_viewport(camera_gt, corner).ok()? - viewport_min;
+                Some(rect.union_point(corner))
+            })
+            // Fallback to cursor position if conversion fails
+            .unwrap_or(Rect::from_center_size(viewport_cursor_pos, Vec2::ZERO))
         } else {
             // Fallback: assume it's a point at the cursor
-            Rect::from_center_size(ctx.cursor_pos, Vec2::ZERO)
-        };
+            Rect::from_center_size(viewport_cursor_pos, Vec2::ZERO)
+        }
+    } else if primary.follow_smoothing > 0.0 {
+        // Smoothly follow the current cursor position.
+        let t = 1.0 - ops::exp(-primary.follow_smoothing * time.delta_secs());
+        let cursor_pos = follow.update(
+            ctx.target,
+            viewport_cursor_pos,
+            ctx.last_cursor_pos - viewport_min,
+            t,
+            time.elapsed(),
+            primary.follow_start_delay,
+        );
+        Rect::from_center_size(cursor_pos, Vec2::ZERO)
+    } else {
+        follow.last = None;
+        Rect::from_center_size(viewport_cursor_pos, Vec2::ZERO)
+    };
+
+    let tooltip_rect = Rect::from_center_size(gt.translation().truncate(), computed.size());
+    let half_size = tooltip_rect.half_size();
+    let mut pos = compute_tooltip_position(
+        &placement,
+        target_rect,
+        tooltip_rect.size(),
+        viewport.size().as_vec2(),
+    );
+
+    // Nudge out of avoid rects.
+    for avoid in &avoid_rects.0 {
+        let rect = Rect::from_center_half_size(pos, half_size);
+        if rect.intersect(*avoid).is_empty() {
+            continue;
+        }
+        let nudge = [
+            Vec2::new(avoid.min.x - rect.max.x, 0.0),
+            Vec2::new(avoid.max.x - rect.min.x, 0.0),
+            Vec2::new(0.0, avoid.min.y - rect.max.y),
+            Vec2::new(0.0, avoid.max.y - rect.min.y),
+        ]
+        .into_iter()
+        .min_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+        .unwrap_or_default();
+        pos += nudge;
+    }
+
+    // Slide from the previous position after transferring between targets.
+    let now = time.elapsed();
+    if let Some((target, placed, last_pos, last_time)) = slide.last
+        && target != ctx.target
+        && placed == entity
+        && primary.transfer_slide > 0
+        && now.saturating_sub(last_time)
+            <= Duration::from_millis(ctx.tooltip.transfer.timeout as u64) + time.delta()
+    {
+        slide.from = Some((last_pos, now));
+    }
+    if let Some((from, start)) = slide.from {
+        let t = now.saturating_sub(start).as_secs_f32() * 1000.0 / primary.transfer_slide as f32;
+        if t < 1.0 {
+            pos = from.lerp(pos, t * t * (3.0 - 2.0 * t));
+        } else {
+            slide.from = None;
+        }
+    }
+    slide.last = Some((ctx.target, entity, pos, now));
 
-        target_rect.center() - target_rect.size() * target_anchor.as_vec() * Vec2::new(-1.0, 1.0)
+    // Apply rounding depending on parity of size, so that the top left corner lands on a physical
+    // pixel even with a fractional scale factor.
+    if primary.snap_to_pixel {
+        let scale_factor = camera.target_scaling_factor().unwrap_or(1.0);
+        pos = Vec2::new(
+            snap_to_pixel(pos.x * scale_factor, computed.size().x),
+            snap_to_pixel(pos.y * scale_factor, computed.size().y),
+        ) / scale_factor;
+    }
+
+    // Convert to the coordinate space of the parent UI node, if any.
+    let parent = if attach {
+        Some(ctx.target)
     } else {
-        ctx.cursor_pos
+        parent.map(ChildOf::parent)
+    };
+    let parent_rect = parent
+        .and_then(|parent| parent_query.get(parent).ok())
+        .map(|(parent_gt, parent_computed)| {
+            Rect::from_center_size(parent_gt.translation().truncate(), parent_computed.size())
+        })
+        .unwrap_or_default();
+
+    // Set position via `Node`.
+    let top_left = pos - tooltip_rect.half_size() - parent_rect.min;
+    node.top = Val::Px(top_left.y);
+    node.left = Val::Px(top_left.x);
+
+    // Set position via `Transform`.
+    // This system has to run after `UiSystem::Layout` so that its size is calculated
+    // from the updated text. However, that means that `Node` positioning will be
+    // delayed by 1 frame. As a workaround, update the `Transform` directly as well.
+    let pos = pos - parent_rect.center();
+    transform.translation.x = pos.x;
+    transform.translation.y = pos.y;
+}
+
+/// A [`SystemParam`] for identifying the camera to display the tooltip on.
+#[derive(SystemParam)]
+struct TooltipCamera<'w, 's> {
+    world_tooltip: Res<'w, WorldTooltip>,
+    target_camera_query: Query<'w, 's, &'static UiTargetCamera>,
+    default_ui_camera: DefaultUiCamera<'w, 's>,
+}
+
+impl TooltipCamera<'_, '_> {
+    /// Get the camera of the world tooltip or target entity, or the default UI camera.
+    fn get(&self, ctx: &TooltipContext) -> Option<Entity> {
+        if ctx.world {
+            return self.world_tooltip.0.as_ref().map(|world| world.camera);
+        }
+        self.target_camera_query
+            .get(ctx.target)
+            .map(UiTargetCamera::entity)
+            .ok()
+            .or(self.default_ui_camera.get())
+    }
+}
+
+/// The state of a tooltip following the cursor for [`TooltipSettings::follow_smoothing`].
+#[derive(Default)]
+struct CursorFollow {
+    /// The target entity, position, and start time of the last placement.
+    last: Option<(Entity, Vec2, Duration)>,
+}
+
+impl CursorFollow {
+    /// Move toward the cursor position by the interpolation factor `t`, starting from the
+    /// activation point for a new target and holding still for the start delay.
+    fn update(
+        &mut self,
+        target: Entity,
+        activation_pos: Vec2,
+        cursor_pos: Vec2,
+        t: f32,
+        now: Duration,
+        start_delay: Duration,
+    ) -> Vec2 {
+        let (pos, start) = match self.last {
+            Some((last_target, pos, start)) if last_target == target => {
+                if now.saturating_sub(start) >= start_delay {
+                    (pos.lerp(cursor_pos, t), start)
+                } else {
+                    (pos, start)
+                }
+            }
+            _ => (activation_pos, now),
+        };
+        self.last = Some((target, pos, start));
+        pos
+    }
+}
+
+/// The state of the slide between targets for [`TooltipSettings::transfer_slide`].
+#[derive(Default)]
+struct TransferSlide {
+    /// The target entity, tooltip entity, position, and time of the last placement.
+    last: Option<(Entity, Entity, Vec2, Duration)>,
+    /// The position and start time of the current slide.
+    from: Option<(Vec2, Duration)>,
+}
+
+/// A [`Component`] on a target entity that an [attached](TooltipPlacement::attach_to_target)
+/// tooltip entity is currently parented to.
+#[derive(Component, Copy, Clone)]
+struct AttachedTooltip {
+    /// The attached tooltip entity.
+    tooltip: Entity,
+    /// The original parent of the tooltip entity.
+    parent: Option<Entity>,
+}
+
+/// Return an attached tooltip entity to its original parent.
+fn detach_tooltip(
+    commands: &mut Commands,
+    attached: &mut Option<Entity>,
+    attached_query: &Query<&AttachedTooltip>,
+) {
+    let target = rq!(attached.take());
+    // The target entity may have been despawned, which already detached the tooltip entity.
+    let attachment = rq!(attached_query.get(target));
+    commands.entity(target).remove::<AttachedTooltip>();
+    restore_parent(commands, attachment.tooltip, attachment.parent);
+}
+
+/// Detach an attached tooltip entity when its target entity is despawned, so it's not despawned
+/// along with the target's children.
+fn detach_tooltip_on_despawn(
+    trigger: Trigger<OnDespawn, AttachedTooltip>,
+    mut world: DeferredWorld,
+) {
+    let target = trigger.target();
+    let &AttachedTooltip { tooltip, parent } = r!(world.get::<AttachedTooltip>(target));
+    // This runs before the target's children are collected for despawning.
+    if let Some(mut children) = world.get_mut::<Children>(target) {
+        children
+            .collection_mut_risky()
+            .retain(|&child| child != tooltip);
+    }
+    restore_parent(&mut world.commands(), tooltip, parent);
+}
+
+// Helper function to set or remove the parent of a tooltip entity
+fn restore_parent(commands: &mut Commands, entity: Entity, parent: Option<Entity>) {
+    let mut entity = r!(commands.get_entity(entity));
+    match parent {
+        Some(parent) => entity.insert(ChildOf(parent)),
+        None => entity.remove::<ChildOf>(),
+    };
+}
+
+/// Compute the center position of a tooltip entity in viewport coordinates.
+///
+/// This applies the anchors, offset, and clamping of the [`TooltipPlacement`], matching the
+/// internal placement system. It does not apply [`TooltipAvoidRects`] or pixel snapping.
+///
+/// The `target_rect` is the target entity's rect in viewport coordinates, or a zero-sized rect at
+/// the cursor position if [`TooltipPlacement::target_anchor`] is `None`.
+///
+/// ```
+/// # use bevy::math::{Rect, Vec2};
+/// # use bevy::sprite::Anchor;
+/// # use pyri_tooltip::{TooltipPlacement, compute_tooltip_position};
+/// let viewport_size = Vec2::new(800.0, 600.0);
+/// let tooltip_size = Vec2::new(100.0, 40.0);
+///
+/// // Cursor placement: centered at the cursor.
+/// let cursor = Rect::from_center_size(Vec2::new(400.0, 300.0), Vec2::ZERO);
+/// let pos = compute_tooltip_position(
+///     &TooltipPlacement::CURSOR_CENTERED,
+///     cursor,
+///     tooltip_size,
+///     viewport_size,
+/// );
+/// assert_eq!(pos, Vec2::new(400.0, 300.0));
+///
+/// // Target placement: above the target entity.
+/// let target = Rect::from_center_size(Vec2::new(400.0, 300.0), Vec2::new(64.0, 64.0));
+/// let pos = compute_tooltip_position(
+///     &TooltipPlacement::from(Anchor::TopCenter),
+///     target,
+///     tooltip_size,
+///     viewport_size,
+/// );
+/// assert_eq!(pos, Vec2::new(400.0, 248.0));
+///
+/// // Clamping: kept within the viewport.
+/// let cursor = Rect::from_center_size(Vec2::new(0.0, 0.0), Vec2::ZERO);
+/// let pos = compute_tooltip_position(
+///     &TooltipPlacement::CURSOR_CENTERED,
+///     cursor,
+///     tooltip_size,
+///     viewport_size,
+/// );
+/// assert_eq!(pos, Vec2::new(50.0, 20.0));
+///
+/// // Bounded clamping: kept within a panel.
+/// let placement = TooltipPlacement {
+///     bounds: Some(Rect::new(200.0, 100.0, 600.0, 500.0)),
+///     ..TooltipPlacement::CURSOR_CENTERED
+/// };
+/// let pos = compute_tooltip_position(&placement, cursor, tooltip_size, viewport_size);
+/// assert_eq!(pos, Vec2::new(250.0, 120.0));
+///
+/// // Screen placement: at the same normalized position for any viewport size.
+/// let placement = TooltipPlacement::normalized_screen(Vec2::new(0.5, 0.9));
+/// let pos = compute_tooltip_position(&placement, cursor, tooltip_size, viewport_size);
+/// assert_eq!(pos, Vec2::new(400.0, 540.0));
+/// let pos = compute_tooltip_position(&placement, cursor, tooltip_size, Vec2::new(1920.0, 1080.0));
+/// assert_eq!(pos, Vec2::new(960.0, 972.0));
+/// ```
+pub fn compute_tooltip_position(
+    placement: &TooltipPlacement,
+    target_rect: Rect,
+    tooltip_size: Vec2,
+    viewport_size: Vec2,
+) -> Vec2 {
+    // Calculate target position.
+    let mut pos = match (placement.screen_position, placement.target_anchor) {
+        (Some(screen_position), _) => screen_position * viewport_size,
+        (None, Some(target_anchor)) => {
+            target_rect.center()
+                - target_rect.size() * target_anchor.as_vec() * Vec2::new(-1.0, 1.0)
+        }
+        (None, None) => target_rect.center(),
     };
 
     // Apply tooltip anchor to target position.
-    let tooltip_rect = Rect::from_center_size(gt.translation().truncate(), computed.size());
-    let tooltip_anchor =
-        tooltip_rect.size() * placement.tooltip_anchor.as_vec() * Vec2::new(-1.0, 1.0);
-    pos += tooltip_anchor;
+    pos += tooltip_size * placement.tooltip_anchor.as_vec() * Vec2::new(-1.0, 1.0);
 
     // Resolve offset `Val`s.
-    let size = viewport.size().as_vec2();
+    let size = viewport_size;
     let offset_x = placement.offset_x.resolve(size.x, size).unwrap_or_default();
     let offset_y = placement.offset_y.resolve(size.y, size).unwrap_or_default();
 
@@ -198,49 +768,37 @@ fn place_tooltip(
     let bottom = bottom.resolve(size.x, size).unwrap_or_default();
 
     // Apply clamping.
-    let half_size = tooltip_rect.half_size();
-    let mut left = half_size.x + left;
-    let mut right = size.x - half_size.x - right;
+    let bounds = placement
+        .bounds
+        .unwrap_or(Rect::from_corners(Vec2::ZERO, size));
+    let half_size = tooltip_size / 2.0;
+    let mut left = bounds.min.x + half_size.x + left;
+    let mut right = bounds.max.x - half_size.x - right;
     if left > right {
         let mid = (left + right) / 2.0;
         left = mid;
         right = mid;
     }
-    let mut top = half_size.y + top;
-    let mut bottom = size.y - half_size.y - bottom;
+    let mut top = bounds.min.y + half_size.y + top;
+    let mut bottom = bounds.max.y - half_size.y - bottom;
     if top > bottom {
         let mid = (top + bottom) / 2.0;
         top = mid;
         bottom = mid;
     }
-    pos = pos.clamp(Vec2::new(left, top), Vec2::new(right, bottom));
+    pos.clamp(Vec2::new(left, top), Vec2::new(right, bottom))
+}
 
-    // Apply rounding depending on parity of size.
-    if tooltip_rect.width().round() % 2.0 < f32::EPSILON {
-        pos.x = round_ties_up(pos.x);
-    } else {
-        pos.x = round_ties_up(pos.x + 0.5) - 0.5;
-    }
-    if tooltip_rect.height().round() % 2.0 < f32::EPSILON {
-        pos.y = round_ties_up(pos.y);
+/// Taken from `bevy_ui`, used in `ui_layout_system`.
+// Helper function to round the center position of a tooltip entity so that its edges land on pixels
+fn snap_to_pixel(pos: f32, size: f32) -> f32 {
+    if size.round() % 2.0 < f32::EPSILON {
+        round_ties_up(pos)
     } else {
-        pos.y = round_ties_up(pos.y + 0.5) - 0.5;
+        round_ties_up(pos + 0.5) - 0.5
     }
-
-    // Set position via `Node`.
-    let top_left = pos - tooltip_rect.half_size();
-    node.top = Val::Px(top_left.y);
-    node.left = Val::Px(top_left.x);
-
-    // Set position via `Transform`.
-    // This system has to run after `UiSystem::Layout` so that its size is calculated
-    // from the updated text. However, that means that `Node` positioning will be
-    // delayed by 1 frame. As a workaround, update the `Transform` directly as well.
-    transform.translation.x = pos.x;
-    transform.translation.y = pos.y;
 }
 
-/// Taken from `bevy_ui`, used in `ui_layout_system`.
 fn round_ties_up(value: f32) -> f32 {
     if value.fract() != -0.5 {
         value.round()
@@ -248,3 +806,289 @@ fn round_ties_up(value: f32) -> f32 {
         value.ceil()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use bevy_asset::{AssetEvent, Assets};
+    use bevy_color::Color;
+    use bevy_ecs::{event::Events, schedule::Schedule, system::RunSystemOnce as _, world::World};
+    use bevy_image::Image;
+    use bevy_math::UVec2;
+    use bevy_render::camera::{
+        ManualTextureViews, OrthographicProjection, Projection, Viewport, camera_system,
+    };
+    use bevy_sprite::Sprite;
+    use bevy_window::{
+        PrimaryWindow, Window, WindowCreated, WindowResized, WindowScaleFactorChanged,
+    };
+
+    use super::*;
+    use crate::{Tooltip, context::TooltipState};
+
+    fn setup(window: Window) -> World {
+        let mut world = World::new();
+        let settings =
+            TooltipSettings::new(&mut world, Entity::PLACEHOLDER, Entity::PLACEHOLDER, true);
+        world.insert_resource(settings);
+        world.init_resource::<TooltipContext>();
+        world.init_resource::<TooltipAvoidRects>();
+        world.init_resource::<WorldTooltip>();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<WindowCreated>>();
+        world.init_resource::<Events<WindowResized>>();
+        world.init_resource::<Events<WindowScaleFactorChanged>>();
+        world.init_resource::<Events<AssetEvent<Image>>>();
+        world.init_resource::<Assets<Image>>();
+        world.init_resource::<ManualTextureViews>();
+        world.spawn((window, PrimaryWindow));
+        world
+    }
+
+    /// Spawn a camera and compute its viewport and projection.
+    fn spawn_camera(world: &mut World, projection: OrthographicProjection) -> Entity {
+        let camera = world
+            .spawn((
+                Camera::default(),
+                Projection::Orthographic(projection),
+                GlobalTransform::default(),
+            ))
+            .id();
+        world.run_system_once(camera_system).unwrap();
+        camera
+    }
+
+    /// Place the tooltip and return its center position.
+    fn place(world: &mut World) -> Vec2 {
+        world.run_system_once(place_tooltip).unwrap();
+        let container = world.resource::<TooltipSettings>().container;
+        world
+            .get::<Transform>(container)
+            .unwrap()
+            .translation
+            .truncate()
+    }
+
+    /// Display a tooltip of the given size at the cursor position.
+    fn show(world: &mut World, tooltip: Tooltip, cursor_pos: Vec2, size: Vec2) -> Entity {
+        let container = world.resource::<TooltipSettings>().container;
+        world.get_mut::<ComputedNode>(container).unwrap().size = size;
+        let mut ctx = world.resource_mut::<TooltipContext>();
+        ctx.state = TooltipState::Active;
+        ctx.tooltip = tooltip;
+        ctx.cursor_pos = cursor_pos;
+        container
+    }
+
+    #[test]
+    fn sprite_target_with_zoomed_camera() {
+        let mut world = setup(Window::default());
+        // Zoom in by 2x.
+        let camera = spawn_camera(
+            &mut world,
+            OrthographicProjection {
+                scale: 0.5,
+                ..OrthographicProjection::default_2d()
+            },
+        );
+        let tooltip = Tooltip::fixed(Anchor::TopCenter, "Hello, world!");
+        let target = world
+            .spawn((
+                tooltip.clone(),
+                Sprite::from_color(Color::WHITE, Vec2::new(100.0, 50.0)),
+                GlobalTransform::default(),
+            ))
+            .id();
+        world.resource_mut::<TooltipContext>().target = target;
+        show(&mut world, tooltip, Vec2::ZERO, Vec2::new(100.0, 40.0));
+
+        // The sprite is 200x100 pixels at the center of the viewport.
+        assert_eq!(place(&mut world), Vec2::new(640.0, 360.0 - 50.0 - 20.0));
+
+        // A camera on the right half of the window.
+        world.get_mut::<Camera>(camera).unwrap().viewport = Some(Viewport {
+            physical_position: UVec2::new(640, 0),
+            physical_size: UVec2::new(640, 720),
+            ..Default::default()
+        });
+        world.run_system_once(camera_system).unwrap();
+        assert_eq!(place(&mut world), Vec2::new(320.0, 360.0 - 50.0 - 20.0));
+    }
+
+    #[test]
+    fn snap_to_physical_pixels() {
+        let mut window = Window::default();
+        window.resolution.set_scale_factor_override(Some(1.5));
+        let mut world = setup(window);
+        spawn_camera(&mut world, OrthographicProjection::default_2d());
+        let size = Vec2::new(101.0, 40.0);
+        show(
+            &mut world,
+            Tooltip::cursor("Hello, world!"),
+            Vec2::new(200.3, 100.3),
+            size,
+        );
+
+        // The top left corner lands on a physical pixel.
+        let top_left = place(&mut world) * 1.5 - size / 2.0;
+        assert_eq!(top_left, top_left.round());
+    }
+
+    #[test]
+    fn unmeasured_tooltip_stays_hidden() {
+        let mut world = setup(Window::default());
+        spawn_camera(&mut world, OrthographicProjection::default_2d());
+        let container = show(
+            &mut world,
+            Tooltip::cursor("Hello, world!"),
+            Vec2::new(100.0, 100.0),
+            Vec2::ZERO,
+        );
+        *world.get_mut::<Visibility>(container).unwrap() = Visibility::Visible;
+        let mut schedule = Schedule::default();
+        schedule.add_systems(place_tooltip);
+
+        // The tooltip stays hidden for as long as layout takes.
+        for _ in 0..3 {
+            schedule.run(&mut world);
+            assert_eq!(
+                world.get::<Visibility>(container),
+                Some(&Visibility::Hidden)
+            );
+        }
+
+        // The tooltip is shown once it has been measured.
+        world.get_mut::<ComputedNode>(container).unwrap().size = Vec2::new(100.0, 40.0);
+        schedule.run(&mut world);
+        assert_eq!(
+            world.get::<Visibility>(container),
+            Some(&Visibility::Visible)
+        );
+    }
+
+    #[test]
+    fn nudge_out_of_avoid_rect() {
+        let mut world = setup(Window::default());
+        spawn_camera(&mut world, OrthographicProjection::default_2d());
+        show(
+            &mut world,
+            Tooltip::cursor("Hello, world!").with_placement(TooltipPlacement::CURSOR_CENTERED),
+            Vec2::new(400.0, 300.0),
+            Vec2::new(100.0, 40.0),
+        );
+
+        // A rect that doesn't overlap the tooltip leaves it in place.
+        world.resource_mut::<TooltipAvoidRects>().0 = vec![Rect::new(0.0, 0.0, 100.0, 100.0)];
+        assert_eq!(place(&mut world), Vec2::new(400.0, 300.0));
+
+        // An overlapping rect nudges the tooltip by the shortest distance out of it (upwards here).
+        world.resource_mut::<TooltipAvoidRects>().0 = vec![Rect::new(380.0, 310.0, 600.0, 400.0)];
+        assert_eq!(place(&mut world), Vec2::new(400.0, 290.0));
+    }
+
+    #[test]
+    fn clamp_within_bounds() {
+        let mut world = setup(Window::default());
+        spawn_camera(&mut world, OrthographicProjection::default_2d());
+        let placement = TooltipPlacement {
+            bounds: Some(Rect::new(200.0, 100.0, 600.0, 500.0)),
+            ..TooltipPlacement::CURSOR_CENTERED
+        };
+        let tooltip = Tooltip::cursor("Hello, world!").with_placement(placement);
+        let size = Vec2::new(100.0, 40.0);
+
+        // A tooltip inside the bounds is unchanged.
+        show(&mut world, tooltip.clone(), Vec2::new(400.0, 300.0), size);
+        assert_eq!(place(&mut world), Vec2::new(400.0, 300.0));
+
+        // A tooltip outside the bounds is clamped within them instead of the window.
+        show(&mut world, tooltip.clone(), Vec2::new(10.0, 10.0), size);
+        assert_eq!(place(&mut world), Vec2::new(250.0, 120.0));
+        show(&mut world, tooltip, Vec2::new(1000.0, 700.0), size);
+        assert_eq!(place(&mut world), Vec2::new(550.0, 480.0));
+    }
+
+    #[test]
+    fn normalized_screen_position() {
+        let mut world = setup(Window::default());
+        let camera = spawn_camera(&mut world, OrthographicProjection::default_2d());
+        let placement = TooltipPlacement::normalized_screen(Vec2::new(0.5, 0.9));
+        show(
+            &mut world,
+            Tooltip::cursor("Hello, world!").with_placement(placement),
+            Vec2::new(100.0, 100.0),
+            Vec2::new(100.0, 40.0),
+        );
+
+        // The tooltip ignores the cursor position.
+        assert_eq!(place(&mut world), Vec2::new(640.0, 648.0));
+
+        // The position is relative to the camera's viewport.
+        world.get_mut::<Camera>(camera).unwrap().viewport = Some(Viewport {
+            physical_position: UVec2::new(640, 0),
+            physical_size: UVec2::new(640, 360),
+            ..Default::default()
+        });
+        world.run_system_once(camera_system).unwrap();
+        assert_eq!(place(&mut world), Vec2::new(320.0, 324.0));
+    }
+
+    #[test]
+    fn despawning_target_detaches_tooltip() {
+        let mut world = World::new();
+        world.add_observer(detach_tooltip_on_despawn);
+        let parent = world.spawn_empty().id();
+        let target = world.spawn_empty().id();
+        let tooltip = world.spawn(ChildOf(target)).id();
+        world.entity_mut(target).insert(AttachedTooltip {
+            tooltip,
+            parent: Some(parent),
+        });
+
+        world.despawn(target);
+        world.flush();
+
+        assert!(world.get_entity(tooltip).is_ok());
+        assert_eq!(world.get::<ChildOf>(tooltip), Some(&ChildOf(parent)));
+    }
+
+    #[test]
+    fn follow_waits_for_start_delay() {
+        let target = Entity::from_raw(1);
+        let start_delay = Duration::from_millis(200);
+        let activation_pos = Vec2::new(100.0, 100.0);
+        let cursor_pos = Vec2::new(200.0, 100.0);
+        let mut follow = CursorFollow::default();
+
+        // The tooltip starts at the activation point.
+        let pos = follow.update(
+            target,
+            activation_pos,
+            cursor_pos,
+            0.5,
+            Duration::ZERO,
+            start_delay,
+        );
+        assert_eq!(pos, activation_pos);
+
+        // The tooltip stays still while it settles.
+        let now = Duration::from_millis(100);
+        let pos = follow.update(target, activation_pos, cursor_pos, 0.5, now, start_delay);
+        assert_eq!(pos, activation_pos);
+
+        // The tooltip follows the cursor once it has settled.
+        let now = Duration::from_millis(200);
+        let pos = follow.update(target, activation_pos, cursor_pos, 0.5, now, start_delay);
+        assert_eq!(pos, Vec2::new(150.0, 100.0));
+
+        // A new target starts over from its activation point.
+        let now = Duration::from_millis(300);
+        let other = Entity::from_raw(2);
+        let pos = follow.update(other, cursor_pos, cursor_pos, 0.5, now, start_delay);
+        assert_eq!(pos, cursor_pos);
+        let now = Duration::from_millis(400);
+        let pos = follow.update(other, cursor_pos, activation_pos, 0.5, now, start_delay);
+        assert_eq!(pos, cursor_pos);
+    }
+}
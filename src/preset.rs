@@ -0,0 +1,256 @@
+use alloc::{borrow::Cow, boxed::Box, collections::BTreeMap, string::String, vec::Vec};
+
+use bevy_app::{App, PreUpdate};
+use bevy_asset::{
+    Asset, AssetApp as _, AssetEvent, AssetLoader, Assets, Handle, LoadContext, io::Reader,
+};
+use bevy_color::Color;
+use bevy_ecs::{
+    change_detection::{DetectChanges as _, Ref},
+    event::EventReader,
+    resource::Resource,
+    schedule::IntoScheduleConfigs as _,
+    system::{Query, Res},
+};
+use bevy_reflect::TypePath;
+use ron::{Options, extensions::Extensions};
+use serde::Deserialize;
+use tiny_bail::prelude::*;
+
+use crate::{Tooltip, TooltipContent, TooltipSystems, rich_text::RichText};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_asset::<TooltipPresetSet>();
+    app.init_asset_loader::<TooltipPresetSetLoader>();
+    app.add_systems(
+        PreUpdate,
+        warn_unknown_presets.in_set(TooltipSystems::Detection),
+    );
+}
+
+/// A named set of [`TooltipPreset`]s loaded from a RON asset with the `.presets.ron` extension.
+///
+/// ```ron
+/// ({
+///     "danger": (delay: 0, font_size: 18.0, color: (1.0, 0.2, 0.2, 1.0)),
+///     "hint": (delay: 1000, hide_delay: 200),
+/// })
+/// ```
+#[derive(Asset, TypePath, Deserialize, Clone, Default, Debug)]
+pub struct TooltipPresetSet(pub BTreeMap<String, TooltipPreset>);
+
+/// A data-driven set of overrides for a [`Tooltip`].
+///
+/// Fields that are `None` leave the tooltip unchanged.
+#[derive(Deserialize, Clone, Default, Debug)]
+#[serde(default)]
+pub struct TooltipPreset {
+    /// Override the [activation delay](crate::TooltipActivation::delay) (in milliseconds).
+    pub delay: Option<u16>,
+    /// Override the [hide delay](crate::TooltipActivation::hide_delay) (in milliseconds).
+    pub hide_delay: Option<u16>,
+    /// Override the [transfer timeout](crate::TooltipTransfer::timeout) (in milliseconds).
+    pub transfer_timeout: Option<u16>,
+    /// Override the [dismissal distance](crate::TooltipDismissal::on_distance) (in logical
+    /// pixels).
+    pub dismiss_distance: Option<f32>,
+    /// Override the font size of each text section.
+    pub font_size: Option<f32>,
+    /// Override the color of each text section, as sRGBA components.
+    pub color: Option<(f32, f32, f32, f32)>,
+}
+
+impl TooltipPreset {
+    /// Apply this preset to a [`Tooltip`].
+    pub fn apply(&self, tooltip: &mut Tooltip) {
+        if let Some(delay) = self.delay {
+            tooltip.activation.delay = delay;
+        }
+        if let Some(hide_delay) = self.hide_delay {
+            tooltip.activation.hide_delay = hide_delay;
+        }
+        if let Some(timeout) = self.transfer_timeout {
+            tooltip.transfer.timeout = timeout;
+        }
+        if let Some(distance) = self.dismiss_distance {
+            tooltip.dismissal.on_distance = distance.into();
+        }
+
+        let contents = core::iter::once(&mut tooltip.content).chain(&mut tooltip.delayed_content);
+        for content in contents {
+            match content {
+                TooltipContent::Primary(text) => self.apply_style(text),
+                TooltipContent::Slots(slots) => {
+                    for (_, text) in slots {
+                        self.apply_style(text);
+                    }
+                }
+                TooltipContent::Custom(_) => {}
+            }
+        }
+    }
+
+    fn apply_style(&self, text: &mut RichText) {
+        for section in &mut text.sections {
+            if let Some(font_size) = self.font_size {
+                section.style.font_size = font_size;
+            }
+            if let Some((r, g, b, a)) = self.color {
+                section.style.color = Color::srgba(r, g, b, a);
+            }
+        }
+    }
+}
+
+/// A [`Resource`] containing the [`TooltipPresetSet`] used to resolve
+/// [`Tooltip::preset`] names.
+///
+/// A tooltip's preset is resolved each time the tooltip is activated or shown, without modifying
+/// its [`Tooltip`], so changes to the preset set (e.g. from hot reloading) apply to the next
+/// activation. A warning is logged for an unknown preset name.
+#[derive(Resource, Clone, Default, Debug)]
+pub struct TooltipPresets(pub Handle<TooltipPresetSet>);
+
+/// An [`AssetLoader`] for [`TooltipPresetSet`] RON files.
+#[derive(Default)]
+struct TooltipPresetSetLoader;
+
+impl AssetLoader for TooltipPresetSetLoader {
+    type Asset = TooltipPresetSet;
+    type Settings = ();
+    type Error = Box<dyn core::error::Error + Send + Sync>;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        // Allow overrides to be written without wrapping them in `Some`.
+        let options = Options::default().with_default_extension(Extensions::IMPLICIT_SOME);
+        Ok(options.from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["presets.ron"]
+    }
+}
+
+fn warn_unknown_presets(
+    presets: Option<Res<TooltipPresets>>,
+    preset_sets: Res<Assets<TooltipPresetSet>>,
+    mut preset_set_events: EventReader<AssetEvent<TooltipPresetSet>>,
+    tooltip_query: Query<Ref<Tooltip>>,
+) {
+    // Check every tooltip when the preset set changes, or only the changed tooltips otherwise.
+    let preset_set_changed = preset_set_events.read().count() > 0;
+    let presets = rq!(presets);
+    let preset_set = rq!(preset_sets.get(&presets.0));
+    let check_all = preset_set_changed || presets.is_changed();
+    for tooltip in &tooltip_query {
+        cq!(check_all || tooltip.is_changed());
+        let name = cq!(tooltip.preset.as_ref());
+        c!(preset_set.0.get(name));
+    }
+}
+
+/// Apply the [preset](Tooltip::preset) of a [`Tooltip`] from a [`TooltipPresetSet`], if any.
+///
+/// The tooltip is left as is if it has no preset, the preset set hasn't loaded, or the preset
+/// name is unknown.
+pub(crate) fn resolve_preset<'a>(
+    preset_set: Option<&TooltipPresetSet>,
+    tooltip: &'a Tooltip,
+) -> Cow<'a, Tooltip> {
+    let preset = tooltip
+        .preset
+        .as_ref()
+        .zip(preset_set)
+        .and_then(|(name, preset_set)| preset_set.0.get(name));
+    match preset {
+        Some(preset) => {
+            let mut tooltip = tooltip.clone();
+            preset.apply(&mut tooltip);
+            Cow::Owned(tooltip)
+        }
+        None => Cow::Borrowed(tooltip),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use alloc::boxed::Box;
+    use std::path::Path;
+
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_asset::{
+        AssetPlugin, AssetServer,
+        io::{
+            AssetSource, AssetSourceId,
+            memory::{Dir, MemoryAssetReader},
+        },
+    };
+
+    use super::*;
+    use crate::TooltipActivation;
+
+    #[test]
+    fn load_and_resolve_preset() {
+        let dir = Dir::default();
+        dir.insert_asset_text(
+            Path::new("tooltips.presets.ron"),
+            r#"({ "danger": (delay: 0, font_size: 18.0, color: (1.0, 0.2, 0.2, 1.0)) })"#,
+        );
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+        );
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()));
+        app.init_asset::<TooltipPresetSet>();
+        app.init_asset_loader::<TooltipPresetSetLoader>();
+
+        let handle = app
+            .world()
+            .resource::<AssetServer>()
+            .load::<TooltipPresetSet>("tooltips.presets.ron");
+        for _ in 0..1000 {
+            app.update();
+            if app
+                .world()
+                .resource::<Assets<TooltipPresetSet>>()
+                .contains(&handle)
+            {
+                break;
+            }
+            std::thread::yield_now();
+        }
+        let preset_sets = app.world().resource::<Assets<TooltipPresetSet>>();
+        let preset_set = preset_sets.get(&handle).expect("preset set should load");
+
+        let tooltip = Tooltip::cursor("Hello, world!").preset("danger");
+        let resolved = resolve_preset(Some(preset_set), &tooltip);
+        assert_eq!(resolved.activation.delay, 0);
+        let TooltipContent::Primary(text) = &resolved.content else {
+            unreachable!();
+        };
+        assert_eq!(text.sections[0].style.font_size, 18.0);
+        assert_eq!(
+            text.sections[0].style.color,
+            Color::srgba(1.0, 0.2, 0.2, 1.0)
+        );
+
+        // The tooltip itself is left unchanged.
+        assert_eq!(tooltip.activation.delay, TooltipActivation::IDLE.delay);
+
+        // An unknown preset name leaves the tooltip as is.
+        let tooltip = Tooltip::cursor("Hello, world!").preset("unknown");
+        let resolved = resolve_preset(Some(preset_set), &tooltip);
+        assert_eq!(resolved.activation.delay, TooltipActivation::IDLE.delay);
+    }
+}
@@ -1,6 +1,6 @@
 #![allow(missing_docs)]
 
-use alloc::{string::String, vec, vec::Vec};
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
 
 use bevy_app::{App, PostUpdate};
 use bevy_asset::Handle;
@@ -12,6 +12,7 @@ use bevy_ecs::{
     entity::Entity,
     hierarchy::Children,
     query::{Changed, With},
+    resource::Resource,
     schedule::{IntoScheduleConfigs as _, SystemSet},
     system::{Commands, Query},
 };
@@ -19,7 +20,7 @@ use bevy_text::{
     Font, FontSmoothing, JustifyText, LineBreak, LineHeight, TextColor, TextFont, TextLayout,
     TextSpan,
 };
-use bevy_ui::{UiSystem, widget::Text};
+use bevy_ui::{TextShadow, UiSystem, widget::Text};
 
 pub(super) fn plugin(app: &mut App) {
     app.configure_sets(PostUpdate, RichTextSystems.before(UiSystem::Prepare));
@@ -41,6 +42,14 @@ fn sync_rich_text_spans(
             Text::default(),
             TextLayout::new(rich_text.justify, rich_text.linebreak_behavior),
         ));
+        match rich_text.shadow {
+            Some(shadow) => {
+                commands.entity(entity).insert(shadow);
+            }
+            None => {
+                commands.entity(entity).remove::<TextShadow>();
+            }
+        }
 
         // Update text span child entities.
         let mut section_idx = 0;
@@ -108,6 +117,8 @@ pub struct RichText {
     pub linebreak_behavior: LineBreak,
     pub line_height: LineHeight,
     pub font_smoothing: FontSmoothing,
+    /// A drop shadow behind the text for readability over busy backgrounds, or `None` to disable.
+    pub shadow: Option<TextShadow>,
 }
 
 impl RichText {
@@ -144,14 +155,94 @@ impl RichText {
         self.font_smoothing = font_smoothing;
         self
     }
+
+    pub const fn with_shadow(mut self, shadow: TextShadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Truncate the text to at most `max_lines` lines (at least one), replacing the excess lines
+    /// with a "… (N more)" line in the style of the last remaining section.
+    ///
+    /// Only explicit line breaks are counted, not line wrapping.
+    ///
+    /// ```
+    /// # use pyri_tooltip::prelude::*;
+    /// let mut text = RichText::from("a\nb\nc\nd");
+    /// text.truncate_lines(2);
+    /// let value = text.sections.iter().map(|section| section.value.as_str());
+    /// assert_eq!(value.collect::<String>(), "a\nb\n… (2 more)");
+    /// ```
+    pub fn truncate_lines(&mut self, max_lines: usize) {
+        let max_lines = max_lines.max(1);
+        let total_lines = 1 + self
+            .sections
+            .iter()
+            .map(|section| section.value.matches('\n').count())
+            .sum::<usize>();
+        if total_lines <= max_lines {
+            return;
+        }
+
+        // Find the line break that starts the first excess line.
+        let mut lines = 1;
+        for (i, section) in self.sections.iter_mut().enumerate() {
+            let Some((cut, _)) = section.value.match_indices('\n').nth(max_lines - lines) else {
+                lines += section.value.matches('\n').count();
+                continue;
+            };
+            section.value.truncate(cut);
+            let style = section.style.clone();
+            self.sections.truncate(i + 1);
+            self.sections.push(TextSection::new(
+                format!("\n… ({} more)", total_lines - max_lines),
+                style,
+            ));
+            return;
+        }
+    }
+}
+
+// Implemented manually because `LineHeight` and `TextShadow` do not implement `PartialEq`.
+impl PartialEq for RichText {
+    fn eq(&self, other: &Self) -> bool {
+        self.sections == other.sections
+            && self.justify == other.justify
+            && self.linebreak_behavior == other.linebreak_behavior
+            && match (self.line_height, other.line_height) {
+                (LineHeight::Px(a), LineHeight::Px(b)) => a == b,
+                (LineHeight::RelativeToFont(a), LineHeight::RelativeToFont(b)) => a == b,
+                _ => false,
+            }
+            && self.font_smoothing == other.font_smoothing
+            && match (self.shadow, other.shadow) {
+                (Some(a), Some(b)) => a.offset == b.offset && a.color == b.color,
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+impl From<&str> for RichText {
+    fn from(value: &str) -> Self {
+        Self::from_section(value, TextStyle::default())
+    }
+}
+
+impl From<String> for RichText {
+    fn from(value: String) -> Self {
+        Self::from_section(value, TextStyle::default())
+    }
 }
 
 /// A section of `RichText` in the shape of Bevy 0.14's `TextSection`.
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, PartialEq, Default, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 pub struct TextSection {
     pub value: String,
     pub style: TextStyle,
+    /// An input action to display the current binding glyph of, in place of `value`.
+    pub glyph: Option<InputGlyph>,
 }
 
 impl TextSection {
@@ -159,6 +250,7 @@ impl TextSection {
         Self {
             value: value.into(),
             style,
+            glyph: None,
         }
     }
 
@@ -166,6 +258,52 @@ impl TextSection {
         Self {
             value: String::new(),
             style,
+            glyph: None,
+        }
+    }
+
+    /// Create a section that displays the binding glyph of an input action, with `fallback` as
+    /// the text label to display if the glyph can't be resolved.
+    pub fn from_glyph(
+        action: impl Into<String>,
+        fallback: impl Into<String>,
+        style: TextStyle,
+    ) -> Self {
+        Self {
+            value: fallback.into(),
+            style,
+            glyph: Some(InputGlyph(action.into())),
+        }
+    }
+}
+
+/// A reference to an input action whose binding glyph should be displayed.
+///
+/// This is resolved by the [`InputGlyphResolver`] resource when a tooltip is shown.
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct InputGlyph(pub String);
+
+/// A [`Resource`] that resolves [`InputGlyph`] action IDs to the current binding glyphs.
+///
+/// If this resource doesn't exist or returns `None`, the section's text is displayed instead.
+#[derive(Resource)]
+pub struct InputGlyphResolver(pub Box<dyn Fn(&str) -> Option<String> + Send + Sync>);
+
+impl InputGlyphResolver {
+    pub fn new(resolve: impl Fn(&str) -> Option<String> + Send + Sync + 'static) -> Self {
+        Self(Box::new(resolve))
+    }
+
+    /// Replace the text of each glyph section with its resolved glyph.
+    pub fn resolve(&self, text: &mut RichText) {
+        for section in &mut text.sections {
+            let Some(InputGlyph(action)) = &section.glyph else {
+                continue;
+            };
+            if let Some(glyph) = (self.0)(action) {
+                section.value = glyph;
+            }
         }
     }
 }
@@ -189,7 +327,7 @@ impl From<String> for TextSection {
 }
 
 /// A text style in the shape of Bevy 0.14's `TextStyle`.
-#[derive(Clone, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 pub struct TextStyle {
     pub font: Handle<Font>,
@@ -206,3 +344,54 @@ impl Default for TextStyle {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn style(font_size: f32) -> TextStyle {
+        TextStyle {
+            font_size,
+            ..Default::default()
+        }
+    }
+
+    fn values(text: &RichText) -> Vec<&str> {
+        text.sections
+            .iter()
+            .map(|section| section.value.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn truncate_lines_within_section() {
+        let mut text = RichText::from("a\nb\nc");
+        text.truncate_lines(2);
+        assert_eq!(values(&text), ["a\nb", "\n… (1 more)"]);
+
+        // Text that already fits is unchanged.
+        let mut text = RichText::from("a\nb");
+        text.truncate_lines(2);
+        assert_eq!(text, RichText::from("a\nb"));
+    }
+
+    #[test]
+    fn truncate_lines_across_sections() {
+        let mut text = RichText::from_sections([
+            TextSection::new("a\n", style(10.0)),
+            TextSection::new("b", style(20.0)),
+            TextSection::new("\nc\nd", style(30.0)),
+        ]);
+        text.truncate_lines(2);
+        assert_eq!(values(&text), ["a\n", "b", "", "\n… (2 more)"]);
+        // The truncation line uses the style of the last remaining section.
+        assert_eq!(text.sections[3].style, style(30.0));
+    }
+
+    #[test]
+    fn truncate_lines_to_at_least_one_line() {
+        let mut text = RichText::from("a\nb\nc");
+        text.truncate_lines(0);
+        assert_eq!(values(&text), ["a", "\n… (2 more)"]);
+    }
+}
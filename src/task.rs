@@ -0,0 +1,40 @@
+use bevy_app::{App, PreUpdate};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    schedule::IntoScheduleConfigs as _,
+    system::{Commands, Query},
+};
+use bevy_tasks::{Task, futures::check_ready};
+
+use crate::{Tooltip, TooltipContent, TooltipSystems};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        PreUpdate,
+        poll_tooltip_content_tasks.before(TooltipSystems::Detection),
+    );
+}
+
+/// A [`Component`] containing a task that builds the [`TooltipContent`] of its entity's
+/// [`Tooltip`] in the background, such as on the
+/// [`AsyncComputeTaskPool`](bevy_tasks::AsyncComputeTaskPool).
+///
+/// This can be used for expensive content that would cause a hitch if built on the main thread.
+/// Until the task completes, the tooltip displays its current content as a placeholder. Once the
+/// task completes, its output replaces [`Tooltip::content`] and this component is removed.
+#[derive(Component, Debug)]
+pub struct TooltipContentTask(pub Task<TooltipContent>);
+
+fn poll_tooltip_content_tasks(
+    mut commands: Commands,
+    mut task_query: Query<(Entity, &mut TooltipContentTask, &mut Tooltip)>,
+) {
+    for (entity, mut task, mut tooltip) in &mut task_query {
+        let Some(content) = check_ready(&mut task.0) else {
+            continue;
+        };
+        tooltip.content = content;
+        commands.entity(entity).remove::<TooltipContentTask>();
+    }
+}
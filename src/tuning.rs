@@ -0,0 +1,223 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use bevy_app::{App, PreUpdate};
+use bevy_asset::{Asset, AssetApp as _, AssetLoader, Assets, Handle, LoadContext, io::Reader};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    schedule::IntoScheduleConfigs as _,
+    system::{Query, Res},
+};
+use bevy_reflect::TypePath;
+use serde::Deserialize;
+use tiny_bail::prelude::*;
+
+use crate::{
+    DismissDistance, Tooltip, TooltipSystems,
+    context::{TargetQuery, update_tooltip_context},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_asset::<TooltipTuningCurve>();
+    app.init_asset_loader::<TooltipTuningCurveLoader>();
+    app.add_systems(
+        PreUpdate,
+        apply_tooltip_tuning
+            .before(update_tooltip_context)
+            .in_set(TooltipSystems::Detection),
+    );
+}
+
+/// Designer-authored curves for tooltip timings and distances as a function of the target
+/// entity's size, loaded from a RON asset with the `.tuning.ron` extension.
+///
+/// Each curve is a list of `(size, value)` keyframes, where the size is the larger of the target
+/// entity's width and height (in logical pixels for a UI node, or in world units for a sprite or
+/// `Mesh2d`). Values are linearly interpolated between
+/// keyframes and clamped to the first and last keyframes. An empty curve leaves the tooltip
+/// unchanged.
+///
+/// ```ron
+/// (
+///     delay: [(0.0, 600.0), (200.0, 200.0)],
+///     dismiss_distance: [(0.0, 32.0), (200.0, 96.0)],
+/// )
+/// ```
+#[derive(Asset, TypePath, Deserialize, Clone, Default, Debug)]
+#[serde(default)]
+pub struct TooltipTuningCurve {
+    /// The [activation delay](crate::TooltipActivation::delay) curve (in milliseconds).
+    pub delay: Vec<(f32, f32)>,
+    /// The [dismissal distance](crate::TooltipDismissal::on_distance) curve (in logical pixels).
+    pub dismiss_distance: Vec<(f32, f32)>,
+}
+
+impl TooltipTuningCurve {
+    /// Sample the activation delay for a target entity of the given size.
+    ///
+    /// ```
+    /// # use pyri_tooltip::TooltipTuningCurve;
+    /// let curve = TooltipTuningCurve {
+    ///     delay: vec![(0.0, 600.0), (200.0, 200.0)],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(curve.sample_delay(100.0), Some(400));
+    /// assert_eq!(curve.sample_delay(500.0), Some(200));
+    /// assert_eq!(TooltipTuningCurve::default().sample_delay(100.0), None);
+    /// ```
+    pub fn sample_delay(&self, size: f32) -> Option<u16> {
+        sample(&self.delay, size).map(|delay| delay.round().clamp(0.0, u16::MAX as f32) as u16)
+    }
+
+    /// Sample the dismissal distance for a target entity of the given size.
+    pub fn sample_dismiss_distance(&self, size: f32) -> Option<DismissDistance> {
+        sample(&self.dismiss_distance, size).map(DismissDistance::Pixels)
+    }
+}
+
+// Helper function to linearly interpolate between sorted keyframes
+fn sample(keyframes: &[(f32, f32)], x: f32) -> Option<f32> {
+    let &(first_x, first_y) = keyframes.first()?;
+    if x <= first_x {
+        return Some(first_y);
+    }
+    for window in keyframes.windows(2) {
+        let [(x0, y0), (x1, y1)] = [window[0], window[1]];
+        if x <= x1 {
+            let t = if x1 > x0 { (x - x0) / (x1 - x0) } else { 1.0 };
+            return Some(y0 + (y1 - y0) * t);
+        }
+    }
+    keyframes.last().map(|&(_, y)| y)
+}
+
+/// A [`Component`] that tunes its entity's [`Tooltip`] from a [`TooltipTuningCurve`].
+///
+/// The sampled values are applied as soon as the curve has loaded and the target entity's size is
+/// known, and again whenever the target entity's size changes. Keyframes must be sorted by size.
+#[derive(Component, Clone, Default, Debug)]
+pub struct TooltipTuning(pub Handle<TooltipTuningCurve>);
+
+/// An [`AssetLoader`] for [`TooltipTuningCurve`] RON files.
+#[derive(Default)]
+struct TooltipTuningCurveLoader;
+
+impl AssetLoader for TooltipTuningCurveLoader {
+    type Asset = TooltipTuningCurve;
+    type Settings = ();
+    type Error = Box<dyn core::error::Error + Send + Sync>;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tuning.ron"]
+    }
+}
+
+fn apply_tooltip_tuning(
+    curves: Res<Assets<TooltipTuningCurve>>,
+    targets: TargetQuery,
+    mut tooltip_query: Query<(Entity, &TooltipTuning, &mut Tooltip)>,
+) {
+    for (entity, tuning, mut tooltip) in &mut tooltip_query {
+        let curve = cq!(curves.get(&tuning.0));
+        let size = cq!(targets.size(entity)).max_element();
+
+        // Only write changed values to avoid triggering change detection every frame.
+        if let Some(delay) = curve.sample_delay(size)
+            && tooltip.activation.delay != delay
+        {
+            tooltip.activation.delay = delay;
+        }
+        if let Some(distance) = curve.sample_dismiss_distance(size)
+            && tooltip.dismissal.on_distance != distance
+        {
+            tooltip.dismissal.on_distance = distance;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use alloc::boxed::Box;
+    use std::path::Path;
+
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_asset::{
+        AssetPlugin, AssetServer,
+        io::{
+            AssetSource, AssetSourceId,
+            memory::{Dir, MemoryAssetReader},
+        },
+    };
+    use bevy_color::Color;
+    use bevy_ecs::system::RunSystemOnce as _;
+    use bevy_math::{Vec2, Vec3};
+    use bevy_sprite::Sprite;
+    use bevy_transform::components::GlobalTransform;
+
+    use super::*;
+
+    #[test]
+    fn load_and_apply_tuning_curve() {
+        let dir = Dir::default();
+        dir.insert_asset_text(
+            Path::new("tooltip.tuning.ron"),
+            "(delay: [(0.0, 600.0), (200.0, 200.0)], dismiss_distance: [(0.0, 32.0)])",
+        );
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+        );
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()));
+        app.init_asset::<TooltipTuningCurve>();
+        app.init_asset_loader::<TooltipTuningCurveLoader>();
+
+        let handle = app
+            .world()
+            .resource::<AssetServer>()
+            .load::<TooltipTuningCurve>("tooltip.tuning.ron");
+        for _ in 0..1000 {
+            app.update();
+            if app
+                .world()
+                .resource::<Assets<TooltipTuningCurve>>()
+                .contains(&handle)
+            {
+                break;
+            }
+            std::thread::yield_now();
+        }
+
+        // A sprite scaled to 100 world units wide.
+        let entity = app
+            .world_mut()
+            .spawn((
+                Tooltip::cursor("Hello, world!"),
+                TooltipTuning(handle),
+                Sprite::from_color(Color::WHITE, Vec2::new(50.0, 20.0)),
+                GlobalTransform::from_scale(Vec3::splat(2.0)),
+            ))
+            .id();
+        app.world_mut()
+            .run_system_once(apply_tooltip_tuning)
+            .unwrap();
+
+        let tooltip = app.world().get::<Tooltip>(entity).unwrap();
+        assert_eq!(tooltip.activation.delay, 400);
+        assert_eq!(tooltip.dismissal.on_distance, DismissDistance::Pixels(32.0));
+    }
+}
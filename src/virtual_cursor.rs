@@ -0,0 +1,73 @@
+use bevy_app::{App, First};
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::ReflectResource;
+use bevy_ecs::{
+    change_detection::DetectChangesMut as _,
+    entity::Entity,
+    query::With,
+    resource::Resource,
+    schedule::IntoScheduleConfigs as _,
+    system::{Commands, Local, Query, Res},
+};
+use bevy_math::Vec2;
+use bevy_picking::{
+    PickSet,
+    pointer::{Location, PointerId, PointerLocation},
+};
+use bevy_render::camera::NormalizedRenderTarget;
+use bevy_window::{PrimaryWindow, WindowRef};
+use tiny_bail::prelude::*;
+use uuid::Uuid;
+
+pub(super) fn plugin(app: &mut App) {
+    #[cfg(feature = "bevy_reflect")]
+    app.register_type::<VirtualCursor>();
+    app.init_resource::<VirtualCursor>();
+    app.add_systems(First, update_virtual_pointer.in_set(PickSet::Input));
+}
+
+/// The [`PointerId`] of the picking pointer driven by [`VirtualCursor`].
+pub const VIRTUAL_POINTER_ID: PointerId =
+    PointerId::Custom(Uuid::from_u128(0x7079_7269_5f74_6f6f_6c74_6970_5f76_6375));
+
+/// A [`Resource`] containing the position of a virtual cursor in the primary window (in logical
+/// pixels), such as one moved by a gamepad stick, or `None` to use the OS cursor.
+///
+/// While this is set, it replaces the OS cursor for tooltip detection and placement. Hovered
+/// entities are detected through a picking pointer with the ID [`VIRTUAL_POINTER_ID`], and
+/// picking events from other pointers are ignored.
+#[derive(Resource, Copy, Clone, Default, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Resource)
+)]
+pub struct VirtualCursor(pub Option<Vec2>);
+
+fn update_virtual_pointer(
+    mut commands: Commands,
+    virtual_cursor: Res<VirtualCursor>,
+    primary_window_query: Query<Entity, With<PrimaryWindow>>,
+    mut pointer_query: Query<(&PointerId, &mut PointerLocation)>,
+    mut spawned: Local<bool>,
+) {
+    // Spawn the virtual pointer the first time it's needed.
+    if !*spawned {
+        rq!(virtual_cursor.0.is_some());
+        *spawned = true;
+        commands.spawn(VIRTUAL_POINTER_ID);
+        return;
+    }
+
+    let (_, mut pointer_location) = r!(pointer_query
+        .iter_mut()
+        .find(|(id, _)| **id == VIRTUAL_POINTER_ID));
+    let location = virtual_cursor.0.and_then(|position| {
+        let window = WindowRef::Primary.normalize(primary_window_query.single().ok())?;
+        Some(Location {
+            target: NormalizedRenderTarget::Window(window),
+            position,
+        })
+    });
+    pointer_location.set_if_neq(PointerLocation { location });
+}
@@ -0,0 +1,205 @@
+use bevy_app::{App, PreUpdate};
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::ReflectResource;
+use bevy_ecs::{
+    change_detection::DetectChanges as _,
+    entity::Entity,
+    event::EventWriter,
+    resource::Resource,
+    schedule::IntoScheduleConfigs as _,
+    system::{Query, Res, ResMut},
+};
+use bevy_math::Vec3;
+use bevy_render::camera::Camera;
+use bevy_transform::components::GlobalTransform;
+
+use crate::{
+    Tooltip, TooltipSettings, TooltipSystems,
+    context::{HideTooltip, ShowTooltip, TooltipContext, TooltipState, update_tooltip_context},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    #[cfg(feature = "bevy_reflect")]
+    app.register_type::<WorldTooltip>();
+    app.init_resource::<WorldTooltip>();
+    app.add_systems(
+        PreUpdate,
+        update_world_tooltip
+            .after(update_tooltip_context)
+            .in_set(TooltipSystems::Detection),
+    );
+}
+
+/// A [`Resource`] that displays a tooltip anchored to a world position instead of a hovered
+/// entity.
+///
+/// The world position is projected to the viewport through the given camera every frame, so the
+/// tooltip follows camera movement. The tooltip is displayed on the same camera. The tooltip is hidden while the position is behind the camera.
+///
+/// While this is set, hovered entities will not activate their tooltips.
+#[derive(Resource, Clone, Default, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Resource)
+)]
+pub struct WorldTooltip(pub Option<WorldTooltipTarget>);
+
+impl WorldTooltip {
+    /// Show a tooltip at a world position, projected through the given camera.
+    pub fn show(&mut self, position: Vec3, camera: Entity, tooltip: Tooltip) {
+        self.0 = Some(WorldTooltipTarget {
+            position,
+            camera,
+            tooltip,
+        });
+    }
+
+    /// Hide the world tooltip.
+    pub fn hide(&mut self) {
+        self.0 = None;
+    }
+}
+
+/// The world position and tooltip parameters of a [`WorldTooltip`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct WorldTooltipTarget {
+    /// The world position to anchor the tooltip to.
+    pub position: Vec3,
+    /// The camera to project the world position through.
+    pub camera: Entity,
+    /// The tooltip to display.
+    ///
+    /// Only the content and placement are used, because the tooltip is not activated by hovering.
+    pub tooltip: Tooltip,
+}
+
+pub(crate) fn world_tooltip_inactive(world_tooltip: Res<WorldTooltip>) -> bool {
+    world_tooltip.0.is_none()
+}
+
+fn update_world_tooltip(
+    world_tooltip: Res<WorldTooltip>,
+    mut ctx: ResMut<TooltipContext>,
+    primary: Res<TooltipSettings>,
+    mut hide_tooltip: EventWriter<HideTooltip>,
+    mut show_tooltip: EventWriter<ShowTooltip>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+) {
+    let old_entity = ctx
+        .displayed_content()
+        .map(|content| content.entity(&primary));
+    let old_world = ctx.world;
+
+    if let Some(world) = &world_tooltip.0 {
+        // Project the world position to window coordinates (`Camera::world_to_viewport` includes
+        // the viewport offset). The tooltip is displayed on the same camera, so placement converts
+        // this back to the same viewport.
+        let window_pos = camera_query
+            .get(world.camera)
+            .ok()
+            .and_then(|(camera, gt)| camera.world_to_viewport(gt, world.position).ok());
+
+        ctx.world = true;
+        ctx.target = Entity::PLACEHOLDER;
+        if world_tooltip.is_changed() || !old_world {
+            ctx.tooltip = world.tooltip.clone();
+        }
+        if let Some(window_pos) = window_pos {
+            ctx.state = TooltipState::Active;
+            ctx.cursor_pos = window_pos;
+        } else {
+            ctx.state = TooltipState::Inactive;
+        }
+    } else if ctx.world {
+        ctx.world = false;
+        ctx.state = TooltipState::Inactive;
+    } else {
+        return;
+    }
+
+    // Only redisplay the tooltip if it has changed.
+    let new_entity = ctx
+        .displayed_content()
+        .map(|content| content.entity(&primary));
+    if old_world && new_entity == old_entity && !world_tooltip.is_changed() {
+        return;
+    }
+    if let Some(entity) = old_entity {
+        hide_tooltip.write(HideTooltip { entity });
+    }
+    if new_entity.is_some() {
+        show_tooltip.write(ShowTooltip);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::{AssetEvent, Assets};
+    use bevy_ecs::{event::Events, schedule::Schedule, world::World};
+    use bevy_image::Image;
+    use bevy_math::{UVec2, Vec2};
+    use bevy_render::camera::{
+        ManualTextureViews, OrthographicProjection, Projection, Viewport, camera_system,
+    };
+    use bevy_window::{
+        PrimaryWindow, Window, WindowCreated, WindowResized, WindowScaleFactorChanged,
+    };
+
+    use super::*;
+
+    #[test]
+    fn world_tooltip_uses_window_coordinates() {
+        let mut world = World::new();
+        let settings =
+            TooltipSettings::new(&mut world, Entity::PLACEHOLDER, Entity::PLACEHOLDER, true);
+        world.insert_resource(settings);
+        world.init_resource::<WorldTooltip>();
+        world.init_resource::<TooltipContext>();
+        world.init_resource::<Events<HideTooltip>>();
+        world.init_resource::<Events<ShowTooltip>>();
+        world.init_resource::<Events<WindowCreated>>();
+        world.init_resource::<Events<WindowResized>>();
+        world.init_resource::<Events<WindowScaleFactorChanged>>();
+        world.init_resource::<Events<AssetEvent<Image>>>();
+        world.init_resource::<Assets<Image>>();
+        world.init_resource::<ManualTextureViews>();
+        world.spawn((Window::default(), PrimaryWindow));
+
+        // A camera on the right half of the window.
+        let camera = world
+            .spawn((
+                Camera {
+                    viewport: Some(Viewport {
+                        physical_position: UVec2::new(640, 0),
+                        physical_size: UVec2::new(640, 720),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                Projection::Orthographic(OrthographicProjection::default_2d()),
+                GlobalTransform::default(),
+            ))
+            .id();
+        world.resource_mut::<WorldTooltip>().show(
+            Vec3::new(10.0, 20.0, 0.0),
+            camera,
+            Tooltip::cursor("Hello, world!"),
+        );
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((camera_system, update_world_tooltip).chain());
+        schedule.run(&mut world);
+        let ctx = world.resource::<TooltipContext>();
+        assert_eq!(ctx.state, TooltipState::Active);
+        assert_eq!(ctx.cursor_pos, Vec2::new(640.0 + 330.0, 340.0));
+        assert_eq!(world.resource::<Events<ShowTooltip>>().len(), 1);
+
+        // The tooltip is not redisplayed while it's unchanged.
+        world.resource_mut::<Events<ShowTooltip>>().clear();
+        schedule.run(&mut world);
+        assert!(world.resource::<Events<HideTooltip>>().is_empty());
+        assert!(world.resource::<Events<ShowTooltip>>().is_empty());
+    }
+}